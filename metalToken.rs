@@ -0,0 +1,4052 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::{
+        burn, close_account, mint_to, set_authority,
+        Burn, CloseAccount, MintTo, SetAuthority, Token2022,
+        spl_token_2022::{
+            extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions},
+            onchain::invoke_transfer_checked,
+            state::Mint as MintState,
+        },
+    },
+    token_2022_extensions::transfer_fee::{
+        harvest_withheld_tokens_to_mint, transfer_fee_set, withdraw_withheld_tokens_from_mint,
+        HarvestWithheldTokensToMint, TransferFeeSetTransferFee, WithdrawWithheldTokensFromMint,
+    },
+    token_interface::{
+        Mint, TokenAccount,
+    },
+};
+
+use transfer_hook_gatekeeper::program::TransferHookGatekeeper;
+
+declare_id!("4Abztzso97KPMy6fdexqNeVKqUUn2KF5aw6Vb99rV8qg");
+
+/// Token-2022 caps transfer fees at 100%
+pub const MAX_FEE_BASIS_POINTS: u16 = 10_000;
+
+/// Upper bound on the governance timelock delay (30 days)
+pub const MAX_TIMELOCK_DELAY: i64 = 30 * 24 * 60 * 60;
+
+/// Number of `Role` variants
+pub const ROLE_COUNT: usize = 6;
+
+/// Maximum number of holders per role; grants beyond this fail with `RoleMembersFull`
+pub const MAX_ROLE_MEMBERS: usize = 16;
+
+/// Upper bound on the multisig approval threshold and on approvals stored per proposal
+pub const MAX_PROPOSAL_APPROVALS: usize = 10;
+
+/// Maximum number of physical products in a redemption policy or product mix
+pub const MAX_PRODUCTS: usize = 8;
+
+/// discriminator + mint + purchase ref + recipient + amount + minted_at
+pub const PURCHASE_RECORD_SPACE: usize = 8 + 32 + 32 + 32 + 8 + 8;
+
+/// Maximum number of recipients settled by a single `batch_mint`
+pub const MAX_BATCH_MINT: usize = 16;
+
+/// Default time operations have to pick up a redemption request (30 days)
+pub const DEFAULT_REDEMPTION_SLA: i64 = 30 * 24 * 60 * 60;
+/// Upper bound on the redemption SLA (365 days)
+pub const MAX_REDEMPTION_SLA: i64 = 365 * 24 * 60 * 60;
+
+/// Single deployment serving every metal mint (gold, silver, platinum, palladium).
+/// Config, role PDAs, the mint authority PDA and redemption PDAs are all keyed by mint.
+#[program]
+pub mod metal_token {
+    use super::*;
+
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+
+
+        let config = &mut ctx.accounts.config;
+        config.admin = *ctx.accounts.admin.key;
+        config.pending_admin = Pubkey::default();
+        config.mint = ctx.accounts.mint.key();
+        config.gatekeeper_program = *ctx.accounts.gatekeeper_program.key;
+        config.redemption_request_counter = 0;
+        config.pause_state = PauseState::default();
+        config.fee_treasury = Pubkey::default();
+        config.recovery_account = Pubkey::default();
+        config.redemption_fee = RedemptionFee::default();
+        config.redemption_sla = DEFAULT_REDEMPTION_SLA;
+        config.timelock_delay = 0;
+        config.governance_action_counter = 0;
+        config.epoch_mint_cap = 0;
+        config.mint_epoch = 0;
+        config.minted_in_epoch = 0;
+        config.role_admins = [Role::DefaultAdmin; ROLE_COUNT];
+        config.multisig_threshold = 0;
+        config.large_mint_threshold = 0;
+        config.proposal_counter = 0;
+        // Role access handled via separate PDA accounts
+
+        // Permissive default policy so redemptions work before `set_redemption_policy` is called
+        let policy = &mut ctx.accounts.redemption_policy;
+        policy.mint = ctx.accounts.mint.key();
+        policy.min_amount = 0;
+        policy.step_amount = 0;
+        policy.products = Vec::new();
+    
+        let cpi_program = ctx.accounts.gatekeeper_program.to_account_info();
+        let cpi_accounts = transfer_hook_gatekeeper::cpi::accounts::Initialize {
+            payer: ctx.accounts.admin.to_account_info(),
+            admin: ctx.accounts.admin.to_account_info(),
+            config: ctx.accounts.gatekeeper_config.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        };
+        
+        // The token config PDA is the gatekeeper's pause authority so pause state can be mirrored by CPI
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        // The asset protection PDA (permanent delegate) and the redemption escrow authority
+        // are exempt from hook checks so seizures and escrow releases can settle.
+        // Role grants made here are honoured by the gatekeeper as well.
+        transfer_hook_gatekeeper::cpi::initialize(
+            cpi_ctx,
+            ctx.accounts.config.key(),
+            ctx.accounts.asset_protection_pda.key(),
+            ctx.accounts.redemption_authority.key(),
+            crate::ID,
+        )?;
+
+        let cpi_accounts = transfer_hook_gatekeeper::cpi::accounts::InitializeExtraAccountMetaList {
+            payer: ctx.accounts.admin.to_account_info(),
+            extra_account_meta_list: ctx.accounts.extra_account_meta_list.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.gatekeeper_program.to_account_info(), cpi_accounts);
+        transfer_hook_gatekeeper::cpi::initialize_extra_account_meta_list(cpi_ctx)?;
+    
+        emit!(TokenInitialized {
+            mint: ctx.accounts.mint.key(),
+            admin: *ctx.accounts.admin.key,
+            gatekeeper_program: *ctx.accounts.gatekeeper_program.key,
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+        });
+    
+        Ok(())
+    }
+
+
+    /// Set the pause flag of every operation explicitly (admin only)
+    pub fn set_pause_state(ctx: Context<SetPauseState>, pause_state: PauseState) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let old_state = config.pause_state;
+        config.pause_state = pause_state;
+
+        // Mirror the flag into the gatekeeper so plain Token-2022 transfers stop as well
+        let mint_key = ctx.accounts.mint.key();
+        let config_seeds = &[b"config".as_ref(), mint_key.as_ref(), &[ctx.bumps.config]];
+        let signer = &[&config_seeds[..]];
+        let cpi_accounts = transfer_hook_gatekeeper::cpi::accounts::SetPauseState {
+            config: ctx.accounts.gatekeeper_config.to_account_info(),
+            authority: config.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.gatekeeper_program.to_account_info(),
+            cpi_accounts,
+            signer,
+        );
+        transfer_hook_gatekeeper::cpi::set_pause_state(cpi_ctx, pause_state.transfer)?;
+        
+        emit!(PauseStateUpdated {
+            mint: mint_key,
+            old_state,
+            new_state: pause_state,
+            authority: *ctx.accounts.admin.key,
+        });
+        Ok(())
+    }
+
+
+    /// Mint against an off-chain purchase request; a reference can only be settled once.
+    pub fn mint_tokens(
+        ctx: Context<MintTokens>,
+        amount: u64,
+        recipient: Pubkey,
+        purchase_ref: [u8; 32],
+    ) -> Result<()> {
+        require!(amount > 0, MetalTokenError::InvalidAmount);
+        require!(!ctx.accounts.config.pause_state.mint, MetalTokenError::ContractPaused);
+        
+        let signer_key = ctx.accounts.admin.key();
+        let role_account = &ctx.accounts.supply_controller_role;
+        require!(
+            role_account.is_active(signer_key, Role::SupplyController),
+            MetalTokenError::Unauthorized
+        );
+
+        // Supply may never exceed the audited metal in custody
+        let max_supply = reserve_cap(&ctx.accounts.reserve_attestation, ctx.accounts.mint.decimals)?;
+        let new_supply = ctx.accounts.mint.supply
+            .checked_add(amount)
+            .ok_or(MetalTokenError::CounterOverflow)?;
+        require!(new_supply <= max_supply, MetalTokenError::ExceedsAttestedReserve);
+        require!(
+            !requires_mint_proposal(&ctx.accounts.config, amount),
+            MetalTokenError::ProposalRequired
+        );
+        consume_mint_capacity(&mut ctx.accounts.config, &mut ctx.accounts.supply_controller_role, amount)?;
+        
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &["mint_authority".as_bytes(), mint_key.as_ref(), &[ctx.bumps.mint_authority_pda]];
+        let signer = &[&seeds[..]];
+        
+        mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.recipient_token_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority_pda.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+
+        let record = &mut ctx.accounts.purchase_record;
+        record.mint = mint_key;
+        record.purchase_ref = purchase_ref;
+        record.recipient = recipient;
+        record.amount = amount;
+        record.minted_at = Clock::get()?.unix_timestamp;
+
+        emit!(TokensMinted {
+            mint: ctx.accounts.mint.key(),
+            to: ctx.accounts.recipient_token_account.key(),
+            amount,
+            authority: *ctx.accounts.mint_authority_pda.key,
+            recipient,
+            purchase_ref,
+        });
+
+        Ok(())
+    }
+
+    /// Settle several purchase requests in one transaction.
+    /// Remaining accounts hold, per entry and in order, the existing recipient token account
+    /// followed by the uninitialized purchase record PDA `[b"purchase", mint, purchase_ref]`.
+    pub fn batch_mint<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchMint<'info>>,
+        entries: Vec<BatchMintEntry>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.pause_state.mint, MetalTokenError::ContractPaused);
+        require!(
+            !entries.is_empty() && entries.len() <= MAX_BATCH_MINT,
+            MetalTokenError::InvalidBatchSize
+        );
+        require!(
+            ctx.remaining_accounts.len() == entries.len() * 2,
+            MetalTokenError::BatchAccountsMismatch
+        );
+
+        let signer_key = ctx.accounts.admin.key();
+        let role_account = &ctx.accounts.supply_controller_role;
+        require!(
+            role_account.is_active(signer_key, Role::SupplyController),
+            MetalTokenError::Unauthorized
+        );
+
+        // The whole batch must fit under the attested reserve
+        let total = entries.iter().try_fold(0u64, |acc, entry| {
+            require!(entry.amount > 0, MetalTokenError::InvalidAmount);
+            acc.checked_add(entry.amount).ok_or(error!(MetalTokenError::CounterOverflow))
+        })?;
+        let max_supply = reserve_cap(&ctx.accounts.reserve_attestation, ctx.accounts.mint.decimals)?;
+        let new_supply = ctx.accounts.mint.supply
+            .checked_add(total)
+            .ok_or(MetalTokenError::CounterOverflow)?;
+        require!(new_supply <= max_supply, MetalTokenError::ExceedsAttestedReserve);
+        require!(
+            !requires_mint_proposal(&ctx.accounts.config, total),
+            MetalTokenError::ProposalRequired
+        );
+        consume_mint_capacity(&mut ctx.accounts.config, &mut ctx.accounts.supply_controller_role, total)?;
+
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &["mint_authority".as_bytes(), mint_key.as_ref(), &[ctx.bumps.mint_authority_pda]];
+        let signer = &[&seeds[..]];
+
+        let now = Clock::get()?.unix_timestamp;
+        for (entry, accounts) in entries.iter().zip(ctx.remaining_accounts.chunks(2)) {
+            let account_info = &accounts[0];
+            create_purchase_record(
+                &accounts[1],
+                &ctx.accounts.admin.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                PurchaseRecord {
+                    mint: mint_key,
+                    purchase_ref: entry.purchase_ref,
+                    recipient: entry.recipient,
+                    amount: entry.amount,
+                    minted_at: now,
+                },
+            )?;
+
+            let recipient_token_account = InterfaceAccount::<TokenAccount>::try_from(account_info)?;
+            require!(
+                recipient_token_account.mint == mint_key && recipient_token_account.owner == entry.recipient,
+                MetalTokenError::InvalidRecipientAccount
+            );
+
+            mint_to(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    MintTo {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        to: account_info.clone(),
+                        authority: ctx.accounts.mint_authority_pda.to_account_info(),
+                    },
+                    signer,
+                ),
+                entry.amount,
+            )?;
+
+            emit!(BatchMintSettled {
+                mint: mint_key,
+                to: account_info.key(),
+                recipient: entry.recipient,
+                amount: entry.amount,
+                purchase_ref: entry.purchase_ref,
+                authority: *ctx.accounts.mint_authority_pda.key,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Move `amount` into a per-request escrow owned by the redemption authority PDA.
+    /// Refunds on cancel, reject or expiry are regular transfers, so any Token-2022 transfer fee
+    /// is withheld from them again and is not compensated.
+    /// The transfer hook's extra accounts must be passed as remaining accounts.
+    pub fn request_redemption<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RequestRedemption<'info>>,
+        amount: u64,
+        delivery: DeliveryDetails,
+        product_mix: Vec<ProductQuantity>,
+    ) -> Result<()> {
+        require!(amount > 0, MetalTokenError::InvalidAmount);
+        require!(!ctx.accounts.config.pause_state.redeem_request, MetalTokenError::ContractPaused);
+        match delivery.method {
+            RedemptionMethod::VaultPickup => require!(
+                delivery.destination_id != [0u8; 32],
+                MetalTokenError::InvalidDeliveryDetails
+            ),
+            RedemptionMethod::CourierDelivery => require!(
+                delivery.shipping_details_hash != [0u8; 32],
+                MetalTokenError::InvalidDeliveryDetails
+            ),
+            RedemptionMethod::CashOut => {}
+        }
+        validate_redemption_amount(&ctx.accounts.redemption_policy, amount, delivery.method, &product_mix)?;
+        let fee_amount = redemption_fee_for(&ctx.accounts.config.redemption_fee, amount)?;
+        
+        require!(
+            ctx.accounts.user_token_account.amount >= amount,
+            MetalTokenError::InsufficientBalance
+        );
+        let available_tokens = ctx.accounts.user_token_account.amount
+            .saturating_sub(ctx.accounts.user_token_account.delegated_amount);
+        
+        let request_id = ctx.accounts.config.redemption_request_counter
+            .checked_add(1)
+            .ok_or(MetalTokenError::CounterOverflow)?;
+        
+        let request = &mut ctx.accounts.redemption_request;
+        request.user = *ctx.accounts.user.key;
+        request.mint = ctx.accounts.mint.key();
+        request.amount = amount;
+        request.status = RedemptionStatus::Pending;
+        request.requested_at = Clock::get()?.unix_timestamp;
+        request.expires_at = request.requested_at
+            .checked_add(ctx.accounts.config.redemption_sla)
+            .ok_or(MetalTokenError::CounterOverflow)?;
+        request.completed_at = 0;
+        request.rejection_reason = 0;
+        request.delivery = delivery;
+        request.product_mix = product_mix.clone();
+        request.fee_amount = fee_amount;
+        request.request_id = request_id;
+        request.escrow_bump = ctx.bumps.redemption_escrow;
+
+        // Gross up for any Token-2022 transfer fee so the escrow receives the full amount
+        let gross_amount = gross_up_for_transfer_fee(&ctx.accounts.mint.to_account_info(), amount)?;
+        require!(
+            available_tokens >= gross_amount,
+            MetalTokenError::InsufficientAvailableTokens
+        );
+
+        invoke_transfer_checked(
+            ctx.accounts.token_program.key,
+            ctx.accounts.user_token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.redemption_escrow.to_account_info(),
+            ctx.accounts.user.to_account_info(),
+            ctx.remaining_accounts,
+            gross_amount,
+            ctx.accounts.mint.decimals,
+            &[],
+        )?;
+        ctx.accounts.redemption_escrow.reload()?;
+        require!(
+            ctx.accounts.redemption_escrow.amount >= amount,
+            MetalTokenError::InsufficientBalance
+        );
+        
+        ctx.accounts.config.redemption_request_counter = request_id;
+
+        let request = &ctx.accounts.redemption_request;
+        emit!(RedemptionRequested {
+            mint: ctx.accounts.mint.key(),
+            user: *ctx.accounts.user.key,
+            request_id,
+            amount,
+            timestamp: request.requested_at,
+            expires_at: request.expires_at,
+            delivery,
+            product_mix,
+            fee_amount,
+        });
+
+        Ok(())
+    }
+
+    /// Settle a processing request: pay the fee from escrow to the treasury and burn the rest.
+    /// The transfer hook's extra accounts must be passed as remaining accounts when a fee is due.
+    pub fn fulfill_redemption<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FulfillRedemption<'info>>,
+    ) -> Result<()> {
+        let signer_key = ctx.accounts.admin.key();
+        let is_paused = ctx.accounts.config.pause_state.redeem_fulfill;
+        let role_account = &ctx.accounts.supply_controller_role;
+        require!(
+            role_account.is_active(signer_key, Role::SupplyController),
+            MetalTokenError::Unauthorized
+        );
+        
+        let request = &mut ctx.accounts.redemption_request;
+        require!(
+            !is_paused && request.status == RedemptionStatus::Processing,
+            MetalTokenError::InvalidRequestStatus
+        );
+        
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[
+            b"redemption_authority".as_ref(),
+            mint_key.as_ref(),
+            &[ctx.bumps.redemption_authority]
+        ];
+        let signer = &[&seeds[..]];
+
+        let escrowed = ctx.accounts.redemption_escrow.amount;
+        let fee_amount = request.fee_amount;
+        require!(fee_amount <= escrowed, MetalTokenError::FeeExceedsAmount);
+
+        // The fee leaves escrow as a regular transfer, so any Token-2022 transfer fee is
+        // withheld from what the treasury receives
+        if fee_amount > 0 {
+            let fee_treasury = ctx.accounts.fee_treasury
+                .as_ref()
+                .ok_or(MetalTokenError::FeeTreasuryNotSet)?;
+            invoke_transfer_checked(
+                ctx.accounts.token_program.key,
+                ctx.accounts.redemption_escrow.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                fee_treasury.to_account_info(),
+                ctx.accounts.redemption_authority.to_account_info(),
+                ctx.remaining_accounts,
+                fee_amount,
+                ctx.accounts.mint.decimals,
+                signer,
+            )?;
+        }
+
+        // Burn the remainder, then close the escrow and return its rent to the user
+        let burned_amount = escrowed - fee_amount;
+        burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.redemption_escrow.to_account_info(),
+                    authority: ctx.accounts.redemption_authority.to_account_info(),
+                },
+                signer,
+            ),
+            burned_amount,
+        )?;
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.redemption_escrow.to_account_info(),
+                destination: ctx.accounts.user.to_account_info(),
+                authority: ctx.accounts.redemption_authority.to_account_info(),
+            },
+            signer,
+        ))?;
+        
+        request.status = RedemptionStatus::Fulfilled;
+        request.completed_at = Clock::get()?.unix_timestamp;
+
+        emit!(RedemptionFulfilled {
+            mint: mint_key,
+            user: request.user,
+            request_id: request.request_id,
+            amount: request.amount,
+            fee_amount,
+            burned_amount,
+            timestamp: request.completed_at,
+        });
+
+        Ok(())
+    }
+    /// Cancel a pending request and return the escrowed tokens.
+    /// If the user has since been blacklisted the tokens go to the recovery account instead.
+    /// The transfer hook's extra accounts must be passed as remaining accounts.
+    pub fn cancel_redemption<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelRedemption<'info>>,
+    ) -> Result<()> {
+        let request = &mut ctx.accounts.redemption_request;
+        require!(request.status == RedemptionStatus::Pending, MetalTokenError::InvalidRequestStatus);
+        
+        let signer_key = ctx.accounts.user.key();
+        let is_own_request = signer_key == request.user;
+        
+        let has_supply_role = ctx.accounts.supply_controller_role
+            .as_ref()
+            .map(|role_account| role_account.is_active(signer_key, Role::SupplyController))
+            .unwrap_or(false);
+            
+        require!(
+            is_own_request || has_supply_role,
+            MetalTokenError::Unauthorized
+        );
+   
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[
+            b"redemption_authority".as_ref(),
+            mint_key.as_ref(),
+            &[ctx.bumps.redemption_authority]
+        ];
+        let signer = &[&seeds[..]];
+
+        let destination = refund_destination(
+            &ctx.accounts.blacklist_entry,
+            &ctx.accounts.user_token_account,
+            &ctx.accounts.recovery_token_account,
+        )?;
+
+        let escrowed = ctx.accounts.redemption_escrow.amount;
+        invoke_transfer_checked(
+            ctx.accounts.token_program.key,
+            ctx.accounts.redemption_escrow.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            destination,
+            ctx.accounts.redemption_authority.to_account_info(),
+            ctx.remaining_accounts,
+            escrowed,
+            ctx.accounts.mint.decimals,
+            signer,
+        )?;
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.redemption_escrow.to_account_info(),
+                destination: ctx.accounts.request_user.to_account_info(),
+                authority: ctx.accounts.redemption_authority.to_account_info(),
+            },
+            signer,
+        ))?;
+        request.status = RedemptionStatus::Cancelled;
+        request.completed_at = Clock::get()?.unix_timestamp;
+    
+        emit!(RedemptionCancelled {
+            mint: mint_key,
+            user: request.user,
+            request_id: request.request_id,
+            amount: escrowed,
+            timestamp: request.completed_at,
+            cancelled_by: signer_key,
+        });
+    
+        Ok(())
+    }
+
+    /// Configure the minimum, step size and deliverable products for redemptions (admin only)
+    pub fn set_redemption_policy(
+        ctx: Context<SetRedemptionPolicy>,
+        min_amount: u64,
+        step_amount: u64,
+        products: Vec<PhysicalProduct>,
+    ) -> Result<()> {
+        require!(products.len() <= MAX_PRODUCTS, MetalTokenError::TooManyProducts);
+        require!(
+            products.iter().all(|product| product.amount > 0),
+            MetalTokenError::InvalidAmount
+        );
+
+        let policy = &mut ctx.accounts.redemption_policy;
+        policy.mint = ctx.accounts.mint.key();
+        policy.min_amount = min_amount;
+        policy.step_amount = step_amount;
+        policy.products = products.clone();
+
+        emit!(RedemptionPolicyUpdated {
+            mint: policy.mint,
+            min_amount,
+            step_amount,
+            products,
+            authority: ctx.accounts.admin.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Reject a pending or processing request, releasing the escrowed tokens back to the user.
+    /// If the user has since been blacklisted the tokens go to the recovery account instead.
+    /// The transfer hook's extra accounts must be passed as remaining accounts.
+    pub fn reject_redemption<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RejectRedemption<'info>>,
+        reason_code: u8,
+    ) -> Result<()> {
+        let signer_key = ctx.accounts.admin.key();
+        let role_account = &ctx.accounts.supply_controller_role;
+        require!(
+            role_account.is_active(signer_key, Role::SupplyController),
+            MetalTokenError::Unauthorized
+        );
+
+        let request = &mut ctx.accounts.redemption_request;
+        require!(
+            request.status == RedemptionStatus::Pending || request.status == RedemptionStatus::Processing,
+            MetalTokenError::InvalidRequestStatus
+        );
+
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[
+            b"redemption_authority".as_ref(),
+            mint_key.as_ref(),
+            &[ctx.bumps.redemption_authority]
+        ];
+        let signer = &[&seeds[..]];
+
+        let destination = refund_destination(
+            &ctx.accounts.blacklist_entry,
+            &ctx.accounts.user_token_account,
+            &ctx.accounts.recovery_token_account,
+        )?;
+
+        let escrowed = ctx.accounts.redemption_escrow.amount;
+        invoke_transfer_checked(
+            ctx.accounts.token_program.key,
+            ctx.accounts.redemption_escrow.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            destination,
+            ctx.accounts.redemption_authority.to_account_info(),
+            ctx.remaining_accounts,
+            escrowed,
+            ctx.accounts.mint.decimals,
+            signer,
+        )?;
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.redemption_escrow.to_account_info(),
+                destination: ctx.accounts.user.to_account_info(),
+                authority: ctx.accounts.redemption_authority.to_account_info(),
+            },
+            signer,
+        ))?;
+
+        let old_status = request.status.clone();
+        request.status = RedemptionStatus::Rejected;
+        request.rejection_reason = reason_code;
+        request.completed_at = Clock::get()?.unix_timestamp;
+
+        emit!(RedemptionRejected {
+            mint: mint_key,
+            user: request.user,
+            request_id: request.request_id,
+            amount: escrowed,
+            old_status,
+            reason_code,
+            timestamp: request.completed_at,
+            rejected_by: signer_key,
+        });
+
+        Ok(())
+    }
+
+    /// Set the SLA applied to new redemption requests (admin only)
+    pub fn set_redemption_sla(ctx: Context<SetRedemptionSla>, redemption_sla: i64) -> Result<()> {
+        require!(
+            redemption_sla > 0 && redemption_sla <= MAX_REDEMPTION_SLA,
+            MetalTokenError::InvalidRedemptionSla
+        );
+        let old_sla = ctx.accounts.config.redemption_sla;
+        ctx.accounts.config.redemption_sla = redemption_sla;
+
+        emit!(RedemptionSlaUpdated {
+            mint: ctx.accounts.mint.key(),
+            old_sla,
+            new_sla: redemption_sla,
+            authority: ctx.accounts.admin.key(),
+        });
+        Ok(())
+    }
+
+    /// Permissionless crank: expire a pending request past its SLA, return the escrowed tokens and refund rent to the user.
+    /// If the user has since been blacklisted the tokens go to the recovery account instead.
+    /// The transfer hook's extra accounts must be passed as remaining accounts.
+    pub fn expire_redemption<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExpireRedemption<'info>>,
+    ) -> Result<()> {
+        let request = &mut ctx.accounts.redemption_request;
+        require!(request.status == RedemptionStatus::Pending, MetalTokenError::InvalidRequestStatus);
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= request.expires_at, MetalTokenError::RedemptionNotExpired);
+
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[
+            b"redemption_authority".as_ref(),
+            mint_key.as_ref(),
+            &[ctx.bumps.redemption_authority]
+        ];
+        let signer = &[&seeds[..]];
+
+        let destination = refund_destination(
+            &ctx.accounts.blacklist_entry,
+            &ctx.accounts.user_token_account,
+            &ctx.accounts.recovery_token_account,
+        )?;
+
+        let escrowed = ctx.accounts.redemption_escrow.amount;
+        invoke_transfer_checked(
+            ctx.accounts.token_program.key,
+            ctx.accounts.redemption_escrow.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            destination,
+            ctx.accounts.redemption_authority.to_account_info(),
+            ctx.remaining_accounts,
+            escrowed,
+            ctx.accounts.mint.decimals,
+            signer,
+        )?;
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.redemption_escrow.to_account_info(),
+                destination: ctx.accounts.user.to_account_info(),
+                authority: ctx.accounts.redemption_authority.to_account_info(),
+            },
+            signer,
+        ))?;
+        request.status = RedemptionStatus::Expired;
+        request.completed_at = now;
+
+        emit!(RedemptionExpired {
+            mint: mint_key,
+            user: request.user,
+            request_id: request.request_id,
+            amount: escrowed,
+            expires_at: request.expires_at,
+            timestamp: now,
+            cranked_by: ctx.accounts.cranker.key(),
+        });
+
+        Ok(())
+    }
+
+    pub fn set_redemption_processing(ctx: Context<UpdateRedemptionStatus>) -> Result<()> {
+        let signer_key = ctx.accounts.admin.key();
+        let role_account = &ctx.accounts.supply_controller_role;
+        require!(
+            role_account.is_active(signer_key, Role::SupplyController),
+            MetalTokenError::Unauthorized
+        );
+
+        let request = &mut ctx.accounts.redemption_request;
+        require!(request.status == RedemptionStatus::Pending, MetalTokenError::InvalidRequestStatus);
+        request.status = RedemptionStatus::Processing;
+
+        emit!(RedemptionStatusUpdated {
+            mint: ctx.accounts.mint.key(),
+            user: request.user,
+            request_id: request.request_id,
+            old_status: RedemptionStatus::Pending,
+            new_status: RedemptionStatus::Processing,
+        });
+
+        Ok(())
+    }
+
+    /// Grant `role` to `user`, optionally bounded to `[valid_from, valid_until)`; `valid_until == 0` never expires.
+    /// Callable by the main admin or an active holder of the role's admin role.
+    /// A role holds at most `MAX_ROLE_MEMBERS` users; further grants fail with `RoleMembersFull`.
+    pub fn grant_role(
+        ctx: Context<GrantRole>,
+        user: Pubkey,
+        role: Role,
+        valid_from: i64,
+        valid_until: i64,
+    ) -> Result<()> {
+        let authority_key = ctx.accounts.authority.key();
+        require!(
+            can_administer_role(&ctx.accounts.config, &ctx.accounts.admin_role, authority_key, role),
+            MetalTokenError::Unauthorized
+        );
+        require!(ctx.accounts.config.timelock_delay == 0, MetalTokenError::TimelockRequired);
+        require!(
+            valid_until == 0 || valid_until > valid_from,
+            MetalTokenError::InvalidRoleValidity
+        );
+
+        let user_role = &mut ctx.accounts.user_role;
+        user_role.user = user;
+        user_role.role = role;
+        user_role.mint_allowance = 0;
+        user_role.valid_from = valid_from;
+        user_role.valid_until = valid_until;
+        user_role.granted_by = authority_key;
+
+        let role_members = &mut ctx.accounts.role_members;
+        role_members.mint = ctx.accounts.mint.key();
+        role_members.role = role;
+        role_members.add(user)?;
+        
+        emit!(RoleAssigned {
+            mint: ctx.accounts.mint.key(),
+            user,
+            role: format!("{:?}", role),
+            authority: authority_key,
+            valid_from,
+            valid_until,
+        });
+        
+        Ok(())
+    }
+
+    /// Revoke `role` from `user`; same authority as `grant_role`
+    pub fn revoke_role(ctx: Context<RevokeRole>, user: Pubkey, role: Role) -> Result<()> {
+        let authority_key = ctx.accounts.authority.key();
+        require!(
+            can_administer_role(&ctx.accounts.config, &ctx.accounts.admin_role, authority_key, role),
+            MetalTokenError::Unauthorized
+        );
+        ctx.accounts.role_members.remove(user);
+
+        emit!(RoleRemoved {
+            mint: ctx.accounts.mint.key(),
+            user,
+            role: format!("{:?}", role),
+            authority: authority_key,
+        });
+        
+        Ok(())
+    }
+
+    /// Give up a role held by the signer
+    pub fn renounce_role(ctx: Context<RenounceRole>, role: Role) -> Result<()> {
+        let user = ctx.accounts.user.key();
+        ctx.accounts.role_members.remove(user);
+        emit!(RoleRemoved {
+            mint: ctx.accounts.mint.key(),
+            user,
+            role: format!("{:?}", role),
+            authority: user,
+        });
+        Ok(())
+    }
+
+    /// Return the current holders of `role` as a borsh-encoded `Vec<Pubkey>` via return data
+    pub fn get_role_members(ctx: Context<GetRoleMembers>, role: Role) -> Result<()> {
+        require!(ctx.accounts.role_members.role == role, MetalTokenError::RoleMismatch);
+        let members = ctx.accounts.role_members.members.try_to_vec()?;
+        anchor_lang::solana_program::program::set_return_data(&members);
+        Ok(())
+    }
+
+    /// Change which role administers `role` (main admin only; queue `SetRoleAdmin` once a timelock is set)
+    pub fn set_role_admin(ctx: Context<SetRoleAdmin>, role: Role, admin_role: Role) -> Result<()> {
+        require!(ctx.accounts.config.timelock_delay == 0, MetalTokenError::TimelockRequired);
+        let config = &mut ctx.accounts.config;
+        let previous_admin_role = config.role_admins[role as usize];
+        config.role_admins[role as usize] = admin_role;
+
+        emit!(RoleAdminChanged {
+            mint: ctx.accounts.mint.key(),
+            role,
+            previous_admin_role,
+            new_admin_role: admin_role,
+        });
+        Ok(())
+    }
+
+    /// Set how much more a SupplyController may mint (admin of the SupplyController role)
+    pub fn set_minter_allowance(ctx: Context<SetMinterAllowance>, user: Pubkey, allowance: u64) -> Result<()> {
+        let authority_key = ctx.accounts.authority.key();
+        require!(
+            can_administer_role(
+                &ctx.accounts.config,
+                &ctx.accounts.admin_role,
+                authority_key,
+                Role::SupplyController,
+            ),
+            MetalTokenError::Unauthorized
+        );
+
+        let minter_role = &mut ctx.accounts.minter_role;
+        let old_allowance = minter_role.mint_allowance;
+        minter_role.mint_allowance = allowance;
+
+        emit!(MinterAllowanceUpdated {
+            mint: ctx.accounts.mint.key(),
+            minter: user,
+            old_allowance,
+            new_allowance: allowance,
+            authority: authority_key,
+        });
+        Ok(())
+    }
+
+    /// Cap the total minted per Solana epoch across all minters; 0 disables the cap
+    pub fn set_epoch_mint_cap(ctx: Context<SetEpochMintCap>, epoch_mint_cap: u64) -> Result<()> {
+        let old_cap = ctx.accounts.config.epoch_mint_cap;
+        ctx.accounts.config.epoch_mint_cap = epoch_mint_cap;
+
+        emit!(EpochMintCapUpdated {
+            mint: ctx.accounts.mint.key(),
+            old_cap,
+            new_cap: epoch_mint_cap,
+            authority: ctx.accounts.admin.key(),
+        });
+        Ok(())
+    }
+
+    /// Propose a new admin; the handover completes only when the proposed key calls `accept_admin`
+    pub fn set_admin(ctx: Context<SetAdmin>, admin: Pubkey) -> Result<()> {
+        let authority_key = ctx.accounts.admin.key();
+        require!(authority_key == ctx.accounts.config.admin, MetalTokenError::Unauthorized);
+        require!(ctx.accounts.config.timelock_delay == 0, MetalTokenError::TimelockRequired);
+        require!(admin != Pubkey::default(), MetalTokenError::InvalidAdmin);
+        ctx.accounts.config.pending_admin = admin;
+        emit!(AdminTransferProposed {
+            mint: ctx.accounts.mint.key(),
+            current_admin: authority_key,
+            pending_admin: admin,
+        });
+        Ok(())
+    }
+
+    /// Complete a pending admin handover (must be signed by the pending admin)
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(config.pending_admin != Pubkey::default(), MetalTokenError::NoPendingAdmin);
+        require!(
+            config.pending_admin == ctx.accounts.pending_admin.key(),
+            MetalTokenError::Unauthorized
+        );
+
+        let old_admin = config.admin;
+        config.admin = config.pending_admin;
+        config.pending_admin = Pubkey::default();
+
+        emit!(AdminTransferAccepted {
+            mint: ctx.accounts.mint.key(),
+            old_admin,
+            new_admin: config.admin,
+        });
+        Ok(())
+    }
+
+    /// Withdraw a pending admin handover (current admin only)
+    pub fn cancel_admin_transfer(ctx: Context<CancelAdminTransfer>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(config.pending_admin != Pubkey::default(), MetalTokenError::NoPendingAdmin);
+
+        let cancelled_admin = config.pending_admin;
+        config.pending_admin = Pubkey::default();
+
+        emit!(AdminTransferCancelled {
+            mint: ctx.accounts.mint.key(),
+            admin: config.admin,
+            cancelled_admin,
+        });
+        Ok(())
+    }
+
+    pub fn wipe_blacklisted_address(ctx: Context<WipeAddress>, amount: u64) -> Result<()> {
+        require!(amount > 0, MetalTokenError::InvalidAmount);
+        require!(!ctx.accounts.config.pause_state.wipe, MetalTokenError::ContractPaused);
+        // Wipes always need four-eyes approval once multisig is enabled
+        require!(ctx.accounts.config.multisig_threshold <= 1, MetalTokenError::ProposalRequired);
+        
+        let signer_key = ctx.accounts.admin.key();
+        let role_account = &ctx.accounts.asset_protection_role;
+        require!(
+            role_account.is_active(signer_key, Role::AssetProtector),
+            MetalTokenError::Unauthorized
+        );
+        
+        require!(
+            ctx.accounts.blacklist_entry.lamports() > 0,
+            MetalTokenError::AddressNotBlacklisted
+        );
+        
+        require!(
+            ctx.accounts.target_token_account.amount >= amount,
+            MetalTokenError::InsufficientBalance
+        );
+        
+        let mint_key = ctx.accounts.mint.key();
+        let asset_protection_seeds = &[
+            b"asset_protection".as_ref(),
+            mint_key.as_ref(),
+            &[ctx.bumps.asset_protection_pda]
+        ];
+        let asset_protection_signer = &[&asset_protection_seeds[..]];
+        
+        burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.target_token_account.to_account_info(),
+                    authority: ctx.accounts.asset_protection_pda.to_account_info(),
+                },
+                asset_protection_signer,
+            ),
+            amount,
+        )?;
+
+        emit!(TokensWiped {
+            mint: mint_key,
+            target_user: *ctx.accounts.target_user.key,
+            amount,
+            authority: *ctx.accounts.asset_protection_pda.key,
+        });
+
+        Ok(())
+    }
+
+    /// Set the custodial token account that receives seized funds (admin only)
+    pub fn set_recovery_account(ctx: Context<SetRecoveryAccount>) -> Result<()> {
+        let old_account = ctx.accounts.config.recovery_account;
+        ctx.accounts.config.recovery_account = ctx.accounts.recovery_token_account.key();
+
+        emit!(RecoveryAccountUpdated {
+            mint: ctx.accounts.mint.key(),
+            old_account,
+            new_account: ctx.accounts.recovery_token_account.key(),
+            authority: ctx.accounts.admin.key(),
+        });
+        Ok(())
+    }
+
+    /// Force-transfer a blacklisted holder's tokens to the recovery account via the permanent delegate.
+    /// The transfer hook's extra accounts must be passed as remaining accounts.
+    pub fn seize_tokens<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SeizeTokens<'info>>,
+        amount: u64,
+        legal_reference_hash: [u8; 32],
+    ) -> Result<()> {
+        require!(amount > 0, MetalTokenError::InvalidAmount);
+        require!(!ctx.accounts.config.pause_state.wipe, MetalTokenError::ContractPaused);
+        require!(legal_reference_hash != [0u8; 32], MetalTokenError::MissingLegalReference);
+        // Seizures need the same four-eyes approval as wipes once multisig is enabled
+        require!(ctx.accounts.config.multisig_threshold <= 1, MetalTokenError::ProposalRequired);
+
+        let signer_key = ctx.accounts.admin.key();
+        let role_account = &ctx.accounts.asset_protection_role;
+        require!(
+            role_account.is_active(signer_key, Role::AssetProtector),
+            MetalTokenError::Unauthorized
+        );
+
+        require!(
+            ctx.accounts.blacklist_entry.lamports() > 0,
+            MetalTokenError::AddressNotBlacklisted
+        );
+
+        require!(
+            ctx.accounts.target_token_account.amount >= amount,
+            MetalTokenError::InsufficientBalance
+        );
+
+        let mint_key = ctx.accounts.mint.key();
+        let asset_protection_seeds = &[
+            b"asset_protection".as_ref(),
+            mint_key.as_ref(),
+            &[ctx.bumps.asset_protection_pda]
+        ];
+        let asset_protection_signer = &[&asset_protection_seeds[..]];
+
+        invoke_transfer_checked(
+            ctx.accounts.token_program.key,
+            ctx.accounts.target_token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.recovery_token_account.to_account_info(),
+            ctx.accounts.asset_protection_pda.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            asset_protection_signer,
+        )?;
+
+        emit!(TokensSeized {
+            mint: mint_key,
+            target_user: *ctx.accounts.target_user.key,
+            from: ctx.accounts.target_token_account.key(),
+            to: ctx.accounts.recovery_token_account.key(),
+            amount,
+            legal_reference_hash,
+            authority: signer_key,
+        });
+
+        Ok(())
+    }
+
+    /// Transfer mint authority from PDA back to a new authority (admin only)
+    pub fn transfer_mint_authority(ctx: Context<TransferMintAuthority>, new_authority: Pubkey) -> Result<()> {
+        // Only the main admin can transfer mint authority
+        require!(
+            ctx.accounts.admin.key() == ctx.accounts.config.admin,
+            MetalTokenError::Unauthorized
+        );
+        require!(ctx.accounts.config.timelock_delay == 0, MetalTokenError::TimelockRequired);
+
+        let mint_key = ctx.accounts.mint.key();
+        let mint_authority_seeds = &[
+            b"mint_authority".as_ref(),
+            mint_key.as_ref(),
+            &[ctx.bumps.mint_authority_pda]
+        ];
+        let signer_seeds = &[&mint_authority_seeds[..]];
+
+        // Transfer mint authority from PDA to new authority
+        set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: ctx.accounts.mint_authority_pda.to_account_info(),
+                    account_or_mint: ctx.accounts.mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType::MintTokens,
+            Some(new_authority),
+        )?;
+
+        emit!(AuthorityTransferred {
+            mint: mint_key,
+            authority_type: "MintTokens".to_string(),
+            old_authority: ctx.accounts.mint_authority_pda.key(),
+            new_authority,
+            transferred_by: ctx.accounts.admin.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Record an audited proof-of-reserve for the mint (Auditor only)
+    pub fn update_reserve_attestation(
+        ctx: Context<UpdateReserveAttestation>,
+        reserve_grams: u64,
+        audited_at: i64,
+        document_hash: [u8; 32],
+    ) -> Result<()> {
+        let signer_key = ctx.accounts.auditor.key();
+        let role_account = &ctx.accounts.auditor_role;
+        require!(
+            role_account.is_active(signer_key, Role::Auditor),
+            MetalTokenError::Unauthorized
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(audited_at > 0 && audited_at <= now, MetalTokenError::InvalidAuditTimestamp);
+
+        let attestation = &mut ctx.accounts.reserve_attestation;
+        require!(audited_at >= attestation.audited_at, MetalTokenError::InvalidAuditTimestamp);
+
+        attestation.mint = ctx.accounts.mint.key();
+        attestation.reserve_grams = reserve_grams;
+        attestation.audited_at = audited_at;
+        attestation.document_hash = document_hash;
+        attestation.auditor = signer_key;
+        attestation.updated_at = now;
+
+        emit!(ReserveAttested {
+            mint: attestation.mint,
+            reserve_grams,
+            audited_at,
+            document_hash,
+            auditor: signer_key,
+            supply: ctx.accounts.mint.supply,
+        });
+
+        Ok(())
+    }
+
+    /// Update the Token-2022 transfer fee (takes effect from the next epoch)
+    pub fn set_transfer_fee(
+        ctx: Context<SetTransferFee>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    ) -> Result<()> {
+        require!(
+            transfer_fee_basis_points <= MAX_FEE_BASIS_POINTS,
+            MetalTokenError::InvalidFeeBasisPoints
+        );
+
+        let signer_key = ctx.accounts.authority.key();
+        let role_account = &ctx.accounts.fee_controller_role;
+        require!(
+            role_account.is_active(signer_key, Role::FeeController),
+            MetalTokenError::Unauthorized
+        );
+
+        let old_rate = {
+            let mint_info = ctx.accounts.mint.to_account_info();
+            let mint_data = mint_info.try_borrow_data()?;
+            let mint_state = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+            let fee_config = mint_state.get_extension::<TransferFeeConfig>()?;
+            u16::from(fee_config.get_epoch_fee(Clock::get()?.epoch).transfer_fee_basis_points)
+        };
+
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[b"fee_controller".as_ref(), mint_key.as_ref(), &[ctx.bumps.fee_controller_pda]];
+        let signer = &[&seeds[..]];
+
+        transfer_fee_set(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferFeeSetTransferFee {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    authority: ctx.accounts.fee_controller_pda.to_account_info(),
+                },
+                signer,
+            ),
+            transfer_fee_basis_points,
+            maximum_fee,
+        )?;
+
+        emit!(FeeRateUpdated {
+            mint: mint_key,
+            old_rate,
+            new_rate: transfer_fee_basis_points,
+            maximum_fee,
+            authority: signer_key,
+        });
+
+        Ok(())
+    }
+
+    /// Configure the redemption fee charged on fulfilment (fixed plus basis points)
+    pub fn set_redemption_fee(
+        ctx: Context<SetRedemptionFee>,
+        fixed_fee: u64,
+        fee_basis_points: u16,
+    ) -> Result<()> {
+        require!(fee_basis_points <= MAX_FEE_BASIS_POINTS, MetalTokenError::InvalidFeeBasisPoints);
+
+        let signer_key = ctx.accounts.authority.key();
+        let role_account = &ctx.accounts.fee_controller_role;
+        require!(
+            role_account.is_active(signer_key, Role::FeeController),
+            MetalTokenError::Unauthorized
+        );
+
+        let old_fee = ctx.accounts.config.redemption_fee;
+        let new_fee = RedemptionFee { fixed_fee, fee_basis_points };
+        ctx.accounts.config.redemption_fee = new_fee;
+
+        emit!(RedemptionFeeUpdated {
+            mint: ctx.accounts.mint.key(),
+            old_fee,
+            new_fee,
+            authority: signer_key,
+        });
+
+        Ok(())
+    }
+
+    /// Set the token account that receives withdrawn transfer fees
+    pub fn set_fee_treasury(ctx: Context<SetFeeTreasury>) -> Result<()> {
+        let signer_key = ctx.accounts.authority.key();
+        let role_account = &ctx.accounts.fee_controller_role;
+        require!(
+            role_account.is_active(signer_key, Role::FeeController),
+            MetalTokenError::Unauthorized
+        );
+
+        let fee_treasury = ctx.accounts.fee_treasury_token_account.key();
+        let old_treasury = ctx.accounts.config.fee_treasury;
+        ctx.accounts.config.fee_treasury = fee_treasury;
+
+        emit!(FeeWalletUpdated {
+            mint: ctx.accounts.mint.key(),
+            old_wallet: old_treasury,
+            new_wallet: fee_treasury,
+            authority: signer_key,
+        });
+
+        Ok(())
+    }
+
+    /// Sweep withheld transfer fees from the token accounts passed as remaining accounts into the mint
+    pub fn harvest_withheld_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, HarvestWithheldFees<'info>>,
+    ) -> Result<()> {
+        require!(!ctx.remaining_accounts.is_empty(), MetalTokenError::InvalidAmount);
+
+        let signer_key = ctx.accounts.authority.key();
+        let role_account = &ctx.accounts.fee_controller_role;
+        require!(
+            role_account.is_active(signer_key, Role::FeeController),
+            MetalTokenError::Unauthorized
+        );
+
+        harvest_withheld_tokens_to_mint(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                HarvestWithheldTokensToMint {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+            ),
+            ctx.remaining_accounts.to_vec(),
+        )?;
+
+        emit!(WithheldTokensHarvested {
+            mint: ctx.accounts.mint.key(),
+            source_count: ctx.remaining_accounts.len() as u32,
+            authority: signer_key,
+        });
+
+        Ok(())
+    }
+
+    /// Withdraw fees withheld on the mint to the configured fee treasury
+    pub fn withdraw_withheld_fees(ctx: Context<WithdrawWithheldFees>) -> Result<()> {
+        let signer_key = ctx.accounts.authority.key();
+        let role_account = &ctx.accounts.fee_controller_role;
+        require!(
+            role_account.is_active(signer_key, Role::FeeController),
+            MetalTokenError::Unauthorized
+        );
+
+        let amount = {
+            let mint_info = ctx.accounts.mint.to_account_info();
+            let mint_data = mint_info.try_borrow_data()?;
+            let mint_state = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+            u64::from(mint_state.get_extension::<TransferFeeConfig>()?.withheld_amount)
+        };
+        require!(amount > 0, MetalTokenError::InvalidAmount);
+
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[b"fee_controller".as_ref(), mint_key.as_ref(), &[ctx.bumps.fee_controller_pda]];
+        let signer = &[&seeds[..]];
+
+        withdraw_withheld_tokens_from_mint(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            WithdrawWithheldTokensFromMint {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                destination: ctx.accounts.fee_treasury.to_account_info(),
+                authority: ctx.accounts.fee_controller_pda.to_account_info(),
+            },
+            signer,
+        ))?;
+
+        emit!(WithheldTokensWithdrawn {
+            mint: mint_key,
+            destination: ctx.accounts.fee_treasury.key(),
+            amount,
+            authority: signer_key,
+        });
+
+        Ok(())
+    }
+
+    /// Schedule a privileged action; it becomes executable once the timelock delay has elapsed
+    pub fn queue_action(ctx: Context<QueueAction>, action: GovernanceAction) -> Result<()> {
+        let authority_key = ctx.accounts.authority.key();
+        let is_main_admin = authority_key == ctx.accounts.config.admin;
+        // Role grants follow the role's admin, as `grant_role` does; everything else is main admin only
+        match action {
+            GovernanceAction::GrantRole { role, .. } => {
+                require!(
+                    can_administer_role(&ctx.accounts.config, &ctx.accounts.admin_role, authority_key, role),
+                    MetalTokenError::Unauthorized
+                );
+            }
+            _ => require!(is_main_admin, MetalTokenError::Unauthorized),
+        }
+
+        match action {
+            GovernanceAction::SetAdmin { new_admin } => {
+                require!(new_admin != Pubkey::default(), MetalTokenError::InvalidAdmin);
+            }
+            GovernanceAction::SetTimelockDelay { delay } => {
+                require!(
+                    (0..=MAX_TIMELOCK_DELAY).contains(&delay),
+                    MetalTokenError::InvalidTimelockDelay
+                );
+            }
+            GovernanceAction::GrantRole { valid_from, valid_until, .. } => {
+                require!(
+                    valid_until == 0 || valid_until > valid_from,
+                    MetalTokenError::InvalidRoleValidity
+                );
+            }
+            GovernanceAction::SetMultisigPolicy { threshold, .. } => {
+                require!(
+                    threshold as usize <= MAX_PROPOSAL_APPROVALS,
+                    MetalTokenError::InvalidMultisigThreshold
+                );
+            }
+            GovernanceAction::TransferMintAuthority { .. } | GovernanceAction::SetRoleAdmin { .. } => {}
+        }
+
+        let action_id = ctx.accounts.config.governance_action_counter
+            .checked_add(1)
+            .ok_or(MetalTokenError::CounterOverflow)?;
+        let queued_at = Clock::get()?.unix_timestamp;
+        let eta = queued_at
+            .checked_add(ctx.accounts.config.timelock_delay)
+            .ok_or(MetalTokenError::CounterOverflow)?;
+
+        let queued_action = &mut ctx.accounts.queued_action;
+        queued_action.mint = ctx.accounts.mint.key();
+        queued_action.action_id = action_id;
+        queued_action.action = action;
+        queued_action.proposer = authority_key;
+        queued_action.queued_at = queued_at;
+        queued_action.eta = eta;
+
+        ctx.accounts.config.governance_action_counter = action_id;
+
+        emit!(ActionQueued {
+            mint: queued_action.mint,
+            action_id,
+            action,
+            proposer: authority_key,
+            eta,
+        });
+
+        Ok(())
+    }
+
+    /// Drop a queued action before it is executed (main admin, an active DefaultAdmin, or the proposer for its own actions)
+    pub fn cancel_action(ctx: Context<CancelAction>) -> Result<()> {
+        let authority_key = ctx.accounts.authority.key();
+        let is_default_admin = ctx.accounts.default_admin_role
+            .as_ref()
+            .map(|role_account| role_account.is_active(authority_key, Role::DefaultAdmin))
+            .unwrap_or(false);
+        require!(
+            authority_key == ctx.accounts.config.admin
+                || is_default_admin
+                || authority_key == ctx.accounts.proposer.key(),
+            MetalTokenError::Unauthorized
+        );
+
+        // The queued action account is closed by the #[account(close)] constraint
+        emit!(ActionCancelled {
+            mint: ctx.accounts.mint.key(),
+            action_id: ctx.accounts.queued_action.action_id,
+            cancelled_by: authority_key,
+        });
+
+        Ok(())
+    }
+
+    /// Execute a queued mint authority transfer (permissionless once the ETA has passed)
+    pub fn execute_transfer_mint_authority(ctx: Context<ExecuteTransferMintAuthority>) -> Result<()> {
+        require_action_ready(&ctx.accounts.queued_action)?;
+        let new_authority = match ctx.accounts.queued_action.action {
+            GovernanceAction::TransferMintAuthority { new_authority } => new_authority,
+            _ => return err!(MetalTokenError::ActionMismatch),
+        };
+
+        let mint_key = ctx.accounts.mint.key();
+        let mint_authority_seeds = &[
+            b"mint_authority".as_ref(),
+            mint_key.as_ref(),
+            &[ctx.bumps.mint_authority_pda]
+        ];
+        let signer_seeds = &[&mint_authority_seeds[..]];
+
+        set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: ctx.accounts.mint_authority_pda.to_account_info(),
+                    account_or_mint: ctx.accounts.mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType::MintTokens,
+            Some(new_authority),
+        )?;
+
+        emit!(AuthorityTransferred {
+            mint: mint_key,
+            authority_type: "MintTokens".to_string(),
+            old_authority: ctx.accounts.mint_authority_pda.key(),
+            new_authority,
+            transferred_by: ctx.accounts.queued_action.proposer,
+        });
+        emit!(ActionExecuted {
+            mint: mint_key,
+            action_id: ctx.accounts.queued_action.action_id,
+            executed_by: ctx.accounts.executor.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Execute a queued admin proposal; the new admin still has to call `accept_admin`
+    pub fn execute_set_admin(ctx: Context<ExecuteGovernanceAction>) -> Result<()> {
+        require_action_ready(&ctx.accounts.queued_action)?;
+        let new_admin = match ctx.accounts.queued_action.action {
+            GovernanceAction::SetAdmin { new_admin } => new_admin,
+            _ => return err!(MetalTokenError::ActionMismatch),
+        };
+
+        let config = &mut ctx.accounts.config;
+        config.pending_admin = new_admin;
+
+        emit!(AdminTransferProposed {
+            mint: config.mint,
+            current_admin: config.admin,
+            pending_admin: new_admin,
+        });
+        emit!(ActionExecuted {
+            mint: config.mint,
+            action_id: ctx.accounts.queued_action.action_id,
+            executed_by: ctx.accounts.executor.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Execute a queued timelock delay change
+    pub fn execute_set_timelock_delay(ctx: Context<ExecuteGovernanceAction>) -> Result<()> {
+        require_action_ready(&ctx.accounts.queued_action)?;
+        let delay = match ctx.accounts.queued_action.action {
+            GovernanceAction::SetTimelockDelay { delay } => delay,
+            _ => return err!(MetalTokenError::ActionMismatch),
+        };
+
+        let config = &mut ctx.accounts.config;
+        let old_delay = config.timelock_delay;
+        config.timelock_delay = delay;
+
+        emit!(TimelockDelayUpdated {
+            mint: config.mint,
+            old_delay,
+            new_delay: delay,
+        });
+        emit!(ActionExecuted {
+            mint: config.mint,
+            action_id: ctx.accounts.queued_action.action_id,
+            executed_by: ctx.accounts.executor.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Execute a queued role grant; `user` and `role` must match the queued action
+    pub fn execute_grant_role(ctx: Context<ExecuteGrantRole>, user: Pubkey, role: Role) -> Result<()> {
+        require_action_ready(&ctx.accounts.queued_action)?;
+        let (valid_from, valid_until) = match ctx.accounts.queued_action.action {
+            GovernanceAction::GrantRole { user: queued_user, role: queued_role, valid_from, valid_until } => {
+                require!(
+                    queued_user == user && queued_role == role,
+                    MetalTokenError::ActionMismatch
+                );
+                (valid_from, valid_until)
+            }
+            _ => return err!(MetalTokenError::ActionMismatch),
+        };
+
+        let user_role = &mut ctx.accounts.user_role;
+        user_role.user = user;
+        user_role.role = role;
+        user_role.mint_allowance = 0;
+        user_role.valid_from = valid_from;
+        user_role.valid_until = valid_until;
+        user_role.granted_by = ctx.accounts.queued_action.proposer;
+
+        let role_members = &mut ctx.accounts.role_members;
+        role_members.mint = ctx.accounts.mint.key();
+        role_members.role = role;
+        role_members.add(user)?;
+
+        emit!(RoleAssigned {
+            mint: ctx.accounts.mint.key(),
+            user,
+            role: format!("{:?}", role),
+            authority: ctx.accounts.queued_action.proposer,
+            valid_from,
+            valid_until,
+        });
+        emit!(ActionExecuted {
+            mint: ctx.accounts.mint.key(),
+            action_id: ctx.accounts.queued_action.action_id,
+            executed_by: ctx.accounts.executor.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Execute a queued change of a role's admin role
+    pub fn execute_set_role_admin(ctx: Context<ExecuteGovernanceAction>) -> Result<()> {
+        require_action_ready(&ctx.accounts.queued_action)?;
+        let (role, admin_role) = match ctx.accounts.queued_action.action {
+            GovernanceAction::SetRoleAdmin { role, admin_role } => (role, admin_role),
+            _ => return err!(MetalTokenError::ActionMismatch),
+        };
+
+        let config = &mut ctx.accounts.config;
+        let previous_admin_role = config.role_admins[role as usize];
+        config.role_admins[role as usize] = admin_role;
+
+        emit!(RoleAdminChanged {
+            mint: config.mint,
+            role,
+            previous_admin_role,
+            new_admin_role: admin_role,
+        });
+        emit!(ActionExecuted {
+            mint: config.mint,
+            action_id: ctx.accounts.queued_action.action_id,
+            executed_by: ctx.accounts.executor.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Require `threshold` approvals for wipes, seizures and mints above `large_mint_threshold`
+    /// (admin only; queue `SetMultisigPolicy` once a timelock is set)
+    pub fn set_multisig_policy(
+        ctx: Context<SetMultisigPolicy>,
+        threshold: u8,
+        large_mint_threshold: u64,
+    ) -> Result<()> {
+        require!(ctx.accounts.config.timelock_delay == 0, MetalTokenError::TimelockRequired);
+        validate_multisig_threshold(
+            threshold,
+            &[
+                ctx.accounts.supply_controller_members.as_deref(),
+                ctx.accounts.asset_protector_members.as_deref(),
+            ],
+        )?;
+        let config = &mut ctx.accounts.config;
+        config.multisig_threshold = threshold;
+        config.large_mint_threshold = large_mint_threshold;
+
+        emit!(MultisigPolicyUpdated {
+            mint: ctx.accounts.mint.key(),
+            threshold,
+            large_mint_threshold,
+            authority: ctx.accounts.admin.key(),
+        });
+        Ok(())
+    }
+
+    /// Execute a queued multisig policy change
+    pub fn execute_set_multisig_policy(ctx: Context<ExecuteSetMultisigPolicy>) -> Result<()> {
+        require_action_ready(&ctx.accounts.queued_action)?;
+        let (threshold, large_mint_threshold) = match ctx.accounts.queued_action.action {
+            GovernanceAction::SetMultisigPolicy { threshold, large_mint_threshold } => (threshold, large_mint_threshold),
+            _ => return err!(MetalTokenError::ActionMismatch),
+        };
+        // Membership may have changed while the action was queued
+        validate_multisig_threshold(
+            threshold,
+            &[
+                ctx.accounts.supply_controller_members.as_deref(),
+                ctx.accounts.asset_protector_members.as_deref(),
+            ],
+        )?;
+
+        let config = &mut ctx.accounts.config;
+        config.multisig_threshold = threshold;
+        config.large_mint_threshold = large_mint_threshold;
+
+        emit!(MultisigPolicyUpdated {
+            mint: config.mint,
+            threshold,
+            large_mint_threshold,
+            authority: ctx.accounts.queued_action.proposer,
+        });
+        emit!(ActionExecuted {
+            mint: config.mint,
+            action_id: ctx.accounts.queued_action.action_id,
+            executed_by: ctx.accounts.executor.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Propose a mint, wipe or seizure; the proposer must hold the role the action requires and counts as the first approval
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+        let proposer_key = ctx.accounts.proposer.key();
+        require!(
+            ctx.accounts.proposer_role.is_active(proposer_key, action.required_role()),
+            MetalTokenError::Unauthorized
+        );
+        match action {
+            ProposalAction::Mint { amount, .. } | ProposalAction::Wipe { amount, .. } => {
+                require!(amount > 0, MetalTokenError::InvalidAmount);
+            }
+            ProposalAction::Seize { amount, legal_reference_hash, .. } => {
+                require!(amount > 0, MetalTokenError::InvalidAmount);
+                require!(legal_reference_hash != [0u8; 32], MetalTokenError::MissingLegalReference);
+            }
+        }
+
+        let proposal_id = ctx.accounts.config.proposal_counter
+            .checked_add(1)
+            .ok_or(MetalTokenError::CounterOverflow)?;
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.mint = ctx.accounts.mint.key();
+        proposal.proposal_id = proposal_id;
+        proposal.action = action;
+        proposal.proposer = proposer_key;
+        proposal.approvals = vec![proposer_key];
+        proposal.created_at = Clock::get()?.unix_timestamp;
+
+        ctx.accounts.config.proposal_counter = proposal_id;
+
+        emit!(ProposalCreated {
+            mint: proposal.mint,
+            proposal_id,
+            action,
+            proposer: proposer_key,
+        });
+        Ok(())
+    }
+
+    /// Approve a proposal as another holder of the required role.
+    /// Remaining accounts hold the role PDA of each existing approval, in order;
+    /// approvals whose grant has expired or been revoked are dropped first.
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        let approver_key = ctx.accounts.approver.key();
+        let proposal = &mut ctx.accounts.proposal;
+        require!(
+            ctx.accounts.approver_role.is_active(approver_key, proposal.action.required_role()),
+            MetalTokenError::Unauthorized
+        );
+        proposal.approvals = active_approvals(proposal, ctx.remaining_accounts)?;
+        require!(
+            !proposal.approvals.contains(&approver_key),
+            MetalTokenError::AlreadyApproved
+        );
+        require!(
+            proposal.approvals.len() < MAX_PROPOSAL_APPROVALS,
+            MetalTokenError::InvalidMultisigThreshold
+        );
+        proposal.approvals.push(approver_key);
+
+        emit!(ProposalApproved {
+            mint: proposal.mint,
+            proposal_id: proposal.proposal_id,
+            approver: approver_key,
+            approvals: proposal.approvals.len() as u8,
+        });
+        Ok(())
+    }
+
+    /// Withdraw a proposal (proposer or admin); rent goes back to the proposer
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        let authority_key = ctx.accounts.authority.key();
+        require!(
+            authority_key == ctx.accounts.proposal.proposer || authority_key == ctx.accounts.config.admin,
+            MetalTokenError::Unauthorized
+        );
+
+        // The proposal account is closed by the #[account(close)] constraint
+        emit!(ProposalCancelled {
+            mint: ctx.accounts.mint.key(),
+            proposal_id: ctx.accounts.proposal.proposal_id,
+            cancelled_by: authority_key,
+        });
+        Ok(())
+    }
+
+    /// Execute an approved mint proposal (permissionless once the threshold is met).
+    /// Counts against the proposer's mint allowance. Remaining accounts hold the role PDA of each approval, in order.
+    pub fn execute_mint_proposal(
+        ctx: Context<ExecuteMintProposal>,
+        recipient: Pubkey,
+        amount: u64,
+        purchase_ref: [u8; 32],
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.pause_state.mint, MetalTokenError::ContractPaused);
+        require_proposal_approved(&ctx.accounts.config, &ctx.accounts.proposal, ctx.remaining_accounts)?;
+        match ctx.accounts.proposal.action {
+            ProposalAction::Mint { recipient: proposed_recipient, amount: proposed_amount, purchase_ref: proposed_ref } => {
+                require!(
+                    proposed_recipient == recipient && proposed_amount == amount && proposed_ref == purchase_ref,
+                    MetalTokenError::ActionMismatch
+                );
+            }
+            _ => return err!(MetalTokenError::ActionMismatch),
+        }
+        require!(
+            ctx.accounts.proposer_role.is_active(ctx.accounts.proposal.proposer, Role::SupplyController),
+            MetalTokenError::Unauthorized
+        );
+
+        let max_supply = reserve_cap(&ctx.accounts.reserve_attestation, ctx.accounts.mint.decimals)?;
+        let new_supply = ctx.accounts.mint.supply
+            .checked_add(amount)
+            .ok_or(MetalTokenError::CounterOverflow)?;
+        require!(new_supply <= max_supply, MetalTokenError::ExceedsAttestedReserve);
+        consume_mint_capacity(&mut ctx.accounts.config, &mut ctx.accounts.proposer_role, amount)?;
+
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &["mint_authority".as_bytes(), mint_key.as_ref(), &[ctx.bumps.mint_authority_pda]];
+        let signer = &[&seeds[..]];
+
+        mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.recipient_token_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority_pda.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+
+        let record = &mut ctx.accounts.purchase_record;
+        record.mint = mint_key;
+        record.purchase_ref = purchase_ref;
+        record.recipient = recipient;
+        record.amount = amount;
+        record.minted_at = Clock::get()?.unix_timestamp;
+
+        emit!(TokensMinted {
+            mint: mint_key,
+            to: ctx.accounts.recipient_token_account.key(),
+            amount,
+            authority: *ctx.accounts.mint_authority_pda.key,
+            recipient,
+            purchase_ref,
+        });
+        emit!(ProposalExecuted {
+            mint: mint_key,
+            proposal_id: ctx.accounts.proposal.proposal_id,
+            executed_by: ctx.accounts.executor.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Execute an approved wipe proposal (permissionless once the threshold is met).
+    /// Remaining accounts hold the role PDA of each approval, in order.
+    pub fn execute_wipe_proposal(
+        ctx: Context<ExecuteWipeProposal>,
+        target_user: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.pause_state.wipe, MetalTokenError::ContractPaused);
+        require_proposal_approved(&ctx.accounts.config, &ctx.accounts.proposal, ctx.remaining_accounts)?;
+        match ctx.accounts.proposal.action {
+            ProposalAction::Wipe { target_user: proposed_target, amount: proposed_amount } => {
+                require!(
+                    proposed_target == target_user && proposed_amount == amount,
+                    MetalTokenError::ActionMismatch
+                );
+            }
+            _ => return err!(MetalTokenError::ActionMismatch),
+        }
+
+        require!(
+            ctx.accounts.blacklist_entry.lamports() > 0,
+            MetalTokenError::AddressNotBlacklisted
+        );
+        require!(
+            ctx.accounts.target_token_account.amount >= amount,
+            MetalTokenError::InsufficientBalance
+        );
+
+        let mint_key = ctx.accounts.mint.key();
+        let asset_protection_seeds = &[
+            b"asset_protection".as_ref(),
+            mint_key.as_ref(),
+            &[ctx.bumps.asset_protection_pda]
+        ];
+        let asset_protection_signer = &[&asset_protection_seeds[..]];
+
+        burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.target_token_account.to_account_info(),
+                    authority: ctx.accounts.asset_protection_pda.to_account_info(),
+                },
+                asset_protection_signer,
+            ),
+            amount,
+        )?;
+
+        emit!(TokensWiped {
+            mint: mint_key,
+            target_user,
+            amount,
+            authority: *ctx.accounts.asset_protection_pda.key,
+        });
+        emit!(ProposalExecuted {
+            mint: mint_key,
+            proposal_id: ctx.accounts.proposal.proposal_id,
+            executed_by: ctx.accounts.executor.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Execute an approved seizure proposal (permissionless once the threshold is met).
+    /// Remaining accounts hold the role PDA of each approval, in order, followed by the transfer hook's extra accounts.
+    pub fn execute_seize_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteSeizeProposal<'info>>,
+        target_user: Pubkey,
+        amount: u64,
+        legal_reference_hash: [u8; 32],
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.pause_state.wipe, MetalTokenError::ContractPaused);
+        require_proposal_approved(&ctx.accounts.config, &ctx.accounts.proposal, ctx.remaining_accounts)?;
+        match ctx.accounts.proposal.action {
+            ProposalAction::Seize {
+                target_user: proposed_target,
+                amount: proposed_amount,
+                legal_reference_hash: proposed_hash,
+            } => {
+                require!(
+                    proposed_target == target_user && proposed_amount == amount && proposed_hash == legal_reference_hash,
+                    MetalTokenError::ActionMismatch
+                );
+            }
+            _ => return err!(MetalTokenError::ActionMismatch),
+        }
+
+        require!(
+            ctx.accounts.blacklist_entry.lamports() > 0,
+            MetalTokenError::AddressNotBlacklisted
+        );
+        require!(
+            ctx.accounts.target_token_account.amount >= amount,
+            MetalTokenError::InsufficientBalance
+        );
+
+        let mint_key = ctx.accounts.mint.key();
+        let asset_protection_seeds = &[
+            b"asset_protection".as_ref(),
+            mint_key.as_ref(),
+            &[ctx.bumps.asset_protection_pda]
+        ];
+        let asset_protection_signer = &[&asset_protection_seeds[..]];
+
+        invoke_transfer_checked(
+            ctx.accounts.token_program.key,
+            ctx.accounts.target_token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.recovery_token_account.to_account_info(),
+            ctx.accounts.asset_protection_pda.to_account_info(),
+            &ctx.remaining_accounts[ctx.accounts.proposal.approvals.len()..],
+            amount,
+            ctx.accounts.mint.decimals,
+            asset_protection_signer,
+        )?;
+
+        emit!(TokensSeized {
+            mint: mint_key,
+            target_user,
+            from: ctx.accounts.target_token_account.key(),
+            to: ctx.accounts.recovery_token_account.key(),
+            amount,
+            legal_reference_hash,
+            authority: ctx.accounts.proposal.proposer,
+        });
+        emit!(ProposalExecuted {
+            mint: mint_key,
+            proposal_id: ctx.accounts.proposal.proposal_id,
+            executed_by: ctx.accounts.executor.key(),
+        });
+
+        Ok(())
+    }
+}
+
+/// Where an escrow refund goes: the user's token account, or the recovery account if the user has since been blacklisted
+fn refund_destination<'info>(
+    blacklist_entry: &UncheckedAccount<'info>,
+    user_token_account: &InterfaceAccount<'info, TokenAccount>,
+    recovery_token_account: &Option<InterfaceAccount<'info, TokenAccount>>,
+) -> Result<AccountInfo<'info>> {
+    if blacklist_entry.lamports() == 0 {
+        return Ok(user_token_account.to_account_info());
+    }
+    Ok(recovery_token_account
+        .as_ref()
+        .ok_or(MetalTokenError::RecoveryAccountNotSet)?
+        .to_account_info())
+}
+
+/// Check a redemption amount against the mint's policy and, for physical delivery, the requested product mix
+fn validate_redemption_amount(
+    policy: &RedemptionPolicy,
+    amount: u64,
+    method: RedemptionMethod,
+    product_mix: &[ProductQuantity],
+) -> Result<()> {
+    require!(amount >= policy.min_amount, MetalTokenError::BelowMinimumRedemption);
+    if policy.step_amount > 0 {
+        require!(amount % policy.step_amount == 0, MetalTokenError::InvalidRedemptionDenomination);
+    }
+    require!(product_mix.len() <= MAX_PRODUCTS, MetalTokenError::TooManyProducts);
+
+    if method == RedemptionMethod::CashOut || policy.products.is_empty() {
+        require!(product_mix.is_empty(), MetalTokenError::InvalidProductMix);
+        return Ok(());
+    }
+
+    let mut total: u64 = 0;
+    for item in product_mix {
+        require!(item.quantity > 0, MetalTokenError::InvalidProductMix);
+        let product = policy.products
+            .iter()
+            .find(|product| product.product_id == item.product_id)
+            .ok_or(MetalTokenError::InvalidProductMix)?;
+        total = product.amount
+            .checked_mul(item.quantity as u64)
+            .and_then(|subtotal| total.checked_add(subtotal))
+            .ok_or(MetalTokenError::CounterOverflow)?;
+    }
+    require!(total == amount, MetalTokenError::InvalidRedemptionDenomination);
+    Ok(())
+}
+
+/// True if `authority` may grant and revoke `role`: the main admin, or an active holder of the role's admin role
+fn can_administer_role(
+    config: &Config,
+    admin_role: &Option<Account<UserRole>>,
+    authority: Pubkey,
+    role: Role,
+) -> bool {
+    authority == config.admin
+        || admin_role
+            .as_ref()
+            .map(|r| r.is_active(authority, config.role_admins[role as usize]))
+            .unwrap_or(false)
+}
+
+/// True if a direct mint of `amount` must instead go through a multisig proposal
+fn requires_mint_proposal(config: &Config, amount: u64) -> bool {
+    config.multisig_threshold > 1 && amount > config.large_mint_threshold
+}
+
+/// Fail unless the proposal has collected the configured number of approvals from active role holders
+fn require_proposal_approved(config: &Config, proposal: &Proposal, approver_roles: &[AccountInfo]) -> Result<()> {
+    let approvals = active_approvals(proposal, approver_roles)?.len();
+    require!(
+        approvals >= config.multisig_threshold.max(1) as usize,
+        MetalTokenError::ThresholdNotMet
+    );
+    Ok(())
+}
+
+/// Approvers whose grant of the proposal's role is still active.
+/// `approver_roles` must start with the `[b"user_role", mint, approver, role]` PDA of each approval, in order;
+/// a revoked grant is a closed account and an expired one fails `is_active`, so neither counts.
+fn active_approvals(proposal: &Proposal, approver_roles: &[AccountInfo]) -> Result<Vec<Pubkey>> {
+    require!(
+        approver_roles.len() >= proposal.approvals.len(),
+        MetalTokenError::ApproverRolesMismatch
+    );
+    let role = proposal.action.required_role();
+    let mut active = Vec::with_capacity(proposal.approvals.len());
+    for (approver, role_info) in proposal.approvals.iter().zip(approver_roles) {
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"user_role", proposal.mint.as_ref(), approver.as_ref(), &[role as u8]],
+            &crate::ID,
+        );
+        require_keys_eq!(role_info.key(), expected, MetalTokenError::ApproverRolesMismatch);
+        if role_info.owner != &crate::ID || role_info.data_is_empty() {
+            continue;
+        }
+        let grant = UserRole::try_deserialize(&mut &role_info.try_borrow_data()?[..])?;
+        if grant.is_active(*approver, role) {
+            active.push(*approver);
+        }
+    }
+    Ok(active)
+}
+
+/// Fail unless `threshold` fits the approval list and, once multisig is on,
+/// each gated role currently has enough members to reach it
+fn validate_multisig_threshold(threshold: u8, role_members: &[Option<&RoleMembers>]) -> Result<()> {
+    require!(
+        threshold as usize <= MAX_PROPOSAL_APPROVALS,
+        MetalTokenError::InvalidMultisigThreshold
+    );
+    if threshold > 1 {
+        for members in role_members {
+            let member_count = members.map(|members| members.member_count).unwrap_or(0);
+            require!(
+                threshold as u32 <= member_count,
+                MetalTokenError::InvalidMultisigThreshold
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Charge a mint against the minter's allowance and the config's per-epoch cap
+fn consume_mint_capacity(config: &mut Config, minter_role: &mut UserRole, amount: u64) -> Result<()> {
+    minter_role.mint_allowance = minter_role.mint_allowance
+        .checked_sub(amount)
+        .ok_or(MetalTokenError::MintAllowanceExceeded)?;
+
+    let epoch = Clock::get()?.epoch;
+    if config.mint_epoch != epoch {
+        config.mint_epoch = epoch;
+        config.minted_in_epoch = 0;
+    }
+    config.minted_in_epoch = config.minted_in_epoch
+        .checked_add(amount)
+        .ok_or(MetalTokenError::CounterOverflow)?;
+    require!(
+        config.epoch_mint_cap == 0 || config.minted_in_epoch <= config.epoch_mint_cap,
+        MetalTokenError::EpochMintCapExceeded
+    );
+    Ok(())
+}
+
+/// Create the purchase record PDA for a batch entry; fails if the reference was already settled.
+/// Like Anchor's `init`, only the rent shortfall is transferred so lamports sent to the PDA beforehand can't block it.
+fn create_purchase_record<'info>(
+    record_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    record: PurchaseRecord,
+) -> Result<()> {
+    let (expected, bump) = Pubkey::find_program_address(
+        &[b"purchase", record.mint.as_ref(), record.purchase_ref.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(record_info.key(), expected, MetalTokenError::InvalidPurchaseRecord);
+    require!(
+        record_info.owner == &System::id() && record_info.data_is_empty(),
+        MetalTokenError::DuplicatePurchaseReference
+    );
+
+    let seeds = &[b"purchase".as_ref(), record.mint.as_ref(), record.purchase_ref.as_ref(), &[bump]];
+    let shortfall = Rent::get()?
+        .minimum_balance(PURCHASE_RECORD_SPACE)
+        .saturating_sub(record_info.lamports());
+    if shortfall > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: record_info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    anchor_lang::system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Allocate {
+                account_to_allocate: record_info.clone(),
+            },
+            &[&seeds[..]],
+        ),
+        PURCHASE_RECORD_SPACE as u64,
+    )?;
+    anchor_lang::system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Assign {
+                account_to_assign: record_info.clone(),
+            },
+            &[&seeds[..]],
+        ),
+        &crate::ID,
+    )?;
+
+    let mut data = record_info.try_borrow_mut_data()?;
+    record.try_serialize(&mut &mut data[..])?;
+    Ok(())
+}
+
+/// Gross amount to send so that `net_amount` arrives after any Token-2022 transfer fee
+fn gross_up_for_transfer_fee(mint_info: &AccountInfo, net_amount: u64) -> Result<u64> {
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    let fee = match mint_state.get_extension::<TransferFeeConfig>() {
+        Ok(fee_config) => fee_config
+            .calculate_inverse_epoch_fee(Clock::get()?.epoch, net_amount)
+            .ok_or(MetalTokenError::CounterOverflow)?,
+        Err(_) => 0,
+    };
+    net_amount
+        .checked_add(fee)
+        .ok_or(error!(MetalTokenError::CounterOverflow))
+}
+
+/// Redemption fee owed on `amount`; the fee must leave something to burn
+fn redemption_fee_for(fee: &RedemptionFee, amount: u64) -> Result<u64> {
+    let variable_fee = (amount as u128)
+        .checked_mul(fee.fee_basis_points as u128)
+        .map(|scaled| scaled / MAX_FEE_BASIS_POINTS as u128)
+        .ok_or(MetalTokenError::CounterOverflow)? as u64;
+    let total = fee.fixed_fee
+        .checked_add(variable_fee)
+        .ok_or(MetalTokenError::CounterOverflow)?;
+    require!(total < amount, MetalTokenError::FeeExceedsAmount);
+    Ok(total)
+}
+
+/// Queued governance actions may only run once their ETA has passed
+fn require_action_ready(queued_action: &QueuedAction) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp >= queued_action.eta,
+        MetalTokenError::TimelockNotElapsed
+    );
+    Ok(())
+}
+
+/// Maximum supply (in base units) backed by the attested reserve.
+/// Assumes 1 token = 1 gram of metal, so the cap is `reserve_grams * 10^decimals`.
+fn reserve_cap(attestation: &ReserveAttestation, decimals: u8) -> Result<u64> {
+    10u64
+        .checked_pow(decimals as u32)
+        .and_then(|unit| attestation.reserve_grams.checked_mul(unit))
+        .ok_or(error!(MetalTokenError::CounterOverflow))
+}
+
+#[derive(Accounts)]
+#[instruction(name: String, symbol: String, uri: String)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub gatekeeper_program: Program<'info, TransferHookGatekeeper>,
+
+    #[account(
+        init, 
+        payer = admin, 
+        space = 8 + 32* 6 + 8 + 5 + 8 + 8 + 8 + 2 + 8 + 8 + 8 + 8 + ROLE_COUNT + 1 + 8 + 8, 
+        seeds = [b"config", mint.key().as_ref()], 
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: This is the config account for the gatekeeper program.
+    #[account(
+        mut,
+        seeds = [b"config", mint.key().as_ref()],
+        bump,
+        seeds::program = gatekeeper_program
+    )]
+    pub gatekeeper_config: AccountInfo<'info>,
+
+    /// CHECK: ExtraAccountMetaList account for the transfer hook
+    #[account(
+        mut,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump,
+        seeds::program = gatekeeper_program
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
+    /// CHECK: Existing mint account with extensions already initialized
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(seeds = [b"mint_authority", mint.key().as_ref()], bump, seeds::program = crate::ID)]
+    /// CHECK: This is a PDA.
+    pub mint_authority_pda: AccountInfo<'info>,
+
+    #[account(seeds = [b"asset_protection", mint.key().as_ref()], bump, seeds::program = crate::ID)]
+    /// CHECK: This is a PDA.
+    pub asset_protection_pda: AccountInfo<'info>,
+
+    #[account(seeds = [b"supply_controller", mint.key().as_ref()], bump, seeds::program = crate::ID)]
+    /// CHECK: This is a PDA.
+    pub supply_controller_pda: AccountInfo<'info>,
+
+    #[account(seeds = [b"fee_controller", mint.key().as_ref()], bump, seeds::program = crate::ID)]
+    /// CHECK: This is a PDA.
+    pub fee_controller_pda: AccountInfo<'info>,
+
+    #[account(seeds = [b"redemption_authority", mint.key().as_ref()], bump, seeds::program = crate::ID)]
+    /// CHECK: This is a PDA.
+    pub redemption_authority: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + 32 + 8 + 8 + 4 + 10 * MAX_PRODUCTS, // discriminator + mint + min + step + products
+        seeds = [b"redemption_policy", mint.key().as_ref()],
+        bump
+    )]
+    pub redemption_policy: Account<'info, RedemptionPolicy>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetPauseState<'info> {
+    #[account(mut, has_one = admin, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: This is the config account for the gatekeeper program.
+    #[account(
+        mut,
+        seeds = [b"config", mint.key().as_ref()],
+        bump,
+        seeds::program = gatekeeper_program
+    )]
+    pub gatekeeper_config: AccountInfo<'info>,
+    #[account(address = config.gatekeeper_program)]
+    pub gatekeeper_program: Program<'info, TransferHookGatekeeper>,
+}
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey, role: Role)]
+pub struct GrantRole<'info> {
+    #[account(mut, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32 + 1 + 8 + 8 + 8 + 32, // discriminator + pubkey + role enum + mint allowance + validity window + granted_by
+        seeds = [b"user_role", mint.key().as_ref(), user.as_ref(), &[role as u8]],
+        bump
+    )]
+    pub user_role: Account<'info, UserRole>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ROLE_MEMBERS_SPACE,
+        seeds = [b"role_members", mint.key().as_ref(), &[role as u8]],
+        bump
+    )]
+    pub role_members: Account<'info, RoleMembers>,
+    /// Authority's grant of the admin role for `role`
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), authority.key().as_ref(), &[config.role_admins[role as usize] as u8]],
+        bump
+    )]
+    pub admin_role: Option<Account<'info, UserRole>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey, role: Role)]
+pub struct RevokeRole<'info> {
+    #[account(mut, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"user_role", mint.key().as_ref(), user.as_ref(), &[role as u8]],
+        bump
+    )]
+    pub user_role: Account<'info, UserRole>,
+    #[account(
+        mut,
+        seeds = [b"role_members", mint.key().as_ref(), &[role as u8]],
+        bump
+    )]
+    pub role_members: Account<'info, RoleMembers>,
+    /// Authority's grant of the admin role for `role`
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), authority.key().as_ref(), &[config.role_admins[role as usize] as u8]],
+        bump
+    )]
+    pub admin_role: Option<Account<'info, UserRole>>,
+}
+
+#[derive(Accounts)]
+#[instruction(role: Role)]
+pub struct RenounceRole<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"user_role", mint.key().as_ref(), user.key().as_ref(), &[role as u8]],
+        bump
+    )]
+    pub user_role: Account<'info, UserRole>,
+    #[account(
+        mut,
+        seeds = [b"role_members", mint.key().as_ref(), &[role as u8]],
+        bump
+    )]
+    pub role_members: Account<'info, RoleMembers>,
+}
+
+#[derive(Accounts)]
+#[instruction(role: Role)]
+pub struct GetRoleMembers<'info> {
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(seeds = [b"role_members", mint.key().as_ref(), &[role as u8]], bump)]
+    pub role_members: Account<'info, RoleMembers>,
+}
+
+#[derive(Accounts)]
+pub struct SetRoleAdmin<'info> {
+    #[account(mut, has_one = admin, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64, recipient: Pubkey, purchase_ref: [u8; 32])]
+pub struct MintTokens<'info> {
+    #[account(mut, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(mut, address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(seeds = [b"mint_authority", mint.key().as_ref()], bump, seeds::program = crate::ID)]
+    /// CHECK: PDA mint authority.
+    pub mint_authority_pda: AccountInfo<'info>,
+    /// CHECK: Recipient account for token minting, validated through associated token account constraints
+    pub recipient: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// Marker that makes a retried mint with the same purchase reference fail
+    #[account(
+        init,
+        payer = admin,
+        space = PURCHASE_RECORD_SPACE,
+        seeds = [b"purchase", mint.key().as_ref(), purchase_ref.as_ref()],
+        bump
+    )]
+    pub purchase_record: Account<'info, PurchaseRecord>,
+    
+    /// Required: Role account for enum-based access
+    #[account(
+        mut,
+        seeds = [b"user_role", mint.key().as_ref(), admin.key().as_ref(), &[Role::SupplyController as u8]],
+        bump
+    )]
+    pub supply_controller_role: Account<'info, UserRole>, 
+    #[account(seeds = [b"reserve", mint.key().as_ref()], bump)]
+    pub reserve_attestation: Account<'info, ReserveAttestation>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BatchMint<'info> {
+    #[account(mut, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(mut, address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(seeds = [b"mint_authority", mint.key().as_ref()], bump, seeds::program = crate::ID)]
+    /// CHECK: PDA mint authority.
+    pub mint_authority_pda: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"user_role", mint.key().as_ref(), admin.key().as_ref(), &[Role::SupplyController as u8]],
+        bump
+    )]
+    pub supply_controller_role: Account<'info, UserRole>,
+    #[account(seeds = [b"reserve", mint.key().as_ref()], bump)]
+    pub reserve_attestation: Account<'info, ReserveAttestation>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RequestRedemption<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut, has_one = mint, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init, 
+        payer = user, 
+        space = 8 + 32 + 32 + 8 + 1 + 8 + 8 + 8 + 1 + (1 + 32 + 32) + (4 + 6 * MAX_PRODUCTS) + 8 + 8 + 1, // discriminator + user + mint + amount + status + timestamps + rejection reason + delivery + product mix + fee + request_id + bump
+        seeds = [b"redemption_request", mint.key().as_ref(), user.key().as_ref(), &config.redemption_request_counter.checked_add(1).unwrap().to_le_bytes()], 
+        bump
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+    #[account(mut, token::mint = mint, token::authority = user)]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = user,
+        seeds = [b"redemption_escrow", mint.key().as_ref(), user.key().as_ref(), &config.redemption_request_counter.checked_add(1).unwrap().to_le_bytes()],
+        bump,
+        token::mint = mint,
+        token::authority = redemption_authority,
+        token::token_program = token_program,
+    )]
+    pub redemption_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [b"redemption_authority", mint.key().as_ref()], bump, seeds::program = crate::ID)]
+    /// CHECK: PDA that owns every redemption escrow of the mint.
+    pub redemption_authority: AccountInfo<'info>,
+    #[account(seeds = [b"redemption_policy", mint.key().as_ref()], bump)]
+    pub redemption_policy: Account<'info, RedemptionPolicy>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetRedemptionPolicy<'info> {
+    #[account(has_one = admin, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + 32 + 8 + 8 + 4 + 10 * MAX_PRODUCTS, // discriminator + mint + min + step + products
+        seeds = [b"redemption_policy", mint.key().as_ref()],
+        bump
+    )]
+    pub redemption_policy: Account<'info, RedemptionPolicy>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FulfillRedemption<'info> {
+    #[account(seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    /// Kept open so the Fulfilled status and delivery details stay on-chain
+    #[account(mut, has_one = user, has_one = mint)]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+    #[account(mut, address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: The user who made the request; receives the escrow rent.
+    #[account(mut)]
+    pub user: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"redemption_escrow", mint.key().as_ref(), user.key().as_ref(), &redemption_request.request_id.to_le_bytes()],
+        bump = redemption_request.escrow_bump,
+    )]
+    pub redemption_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [b"redemption_authority", mint.key().as_ref()], bump, seeds::program = crate::ID)]
+    /// CHECK: PDA that owns every redemption escrow of the mint.
+    pub redemption_authority: AccountInfo<'info>,
+    /// Required only when the request carries a fee
+    #[account(mut, address = config.fee_treasury @ MetalTokenError::FeeTreasuryNotSet, token::mint = mint)]
+    pub fee_treasury: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// Required: Role account for supply controller access
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), admin.key().as_ref(), &[Role::SupplyController as u8]],
+        bump
+    )]
+    pub supply_controller_role: Account<'info, UserRole>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct CancelRedemption<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, close = request_user, has_one = mint)]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+    /// CHECK: The user who made the request; paid and gets back the request and escrow rent.
+    #[account(mut, address = redemption_request.user)]
+    pub request_user: AccountInfo<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, token::mint = mint, token::authority = redemption_request.user)]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"redemption_escrow", mint.key().as_ref(), redemption_request.user.as_ref(), &redemption_request.request_id.to_le_bytes()],
+        bump = redemption_request.escrow_bump,
+    )]
+    pub redemption_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [b"redemption_authority", mint.key().as_ref()], bump, seeds::program = crate::ID)]
+    /// CHECK: PDA that owns every redemption escrow of the mint.
+    pub redemption_authority: AccountInfo<'info>,
+    #[account(
+        seeds = [b"blacklist", mint.key().as_ref(), redemption_request.user.as_ref()],
+        bump,
+        seeds::program = config.gatekeeper_program
+    )]
+    /// CHECK: Gatekeeper blacklist marker for the user; may not exist.
+    pub blacklist_entry: UncheckedAccount<'info>,
+    /// Required only when the user is blacklisted
+    #[account(mut, address = config.recovery_account @ MetalTokenError::RecoveryAccountNotSet, token::mint = mint)]
+    pub recovery_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Optional: Role account for supply controller access (only needed if not own request)
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), user.key().as_ref(), &[Role::SupplyController as u8]],
+        bump
+    )]
+    pub supply_controller_role: Option<Account<'info, UserRole>>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct SetRedemptionSla<'info> {
+    #[account(mut, has_one = admin, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct SetMinterAllowance<'info> {
+    pub authority: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"user_role", mint.key().as_ref(), user.as_ref(), &[Role::SupplyController as u8]],
+        bump
+    )]
+    pub minter_role: Account<'info, UserRole>,
+    /// Holder grant of the SupplyController role's admin role
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), authority.key().as_ref(), &[config.role_admins[Role::SupplyController as usize] as u8]],
+        bump
+    )]
+    pub admin_role: Option<Account<'info, UserRole>>,
+}
+
+#[derive(Accounts)]
+pub struct SetEpochMintCap<'info> {
+    #[account(mut, has_one = admin, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+#[derive(Accounts)]
+pub struct ExpireRedemption<'info> {
+    pub cranker: Signer<'info>,
+    #[account(seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, close = user, has_one = user, has_one = mint)]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: The user who made the request; receives the rent refund.
+    #[account(mut)]
+    pub user: AccountInfo<'info>,
+    #[account(mut, token::mint = mint, token::authority = user)]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"redemption_escrow", mint.key().as_ref(), user.key().as_ref(), &redemption_request.request_id.to_le_bytes()],
+        bump = redemption_request.escrow_bump,
+    )]
+    pub redemption_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [b"redemption_authority", mint.key().as_ref()], bump, seeds::program = crate::ID)]
+    /// CHECK: PDA that owns every redemption escrow of the mint.
+    pub redemption_authority: AccountInfo<'info>,
+    #[account(
+        seeds = [b"blacklist", mint.key().as_ref(), user.key().as_ref()],
+        bump,
+        seeds::program = config.gatekeeper_program
+    )]
+    /// CHECK: Gatekeeper blacklist marker for the user; may not exist.
+    pub blacklist_entry: UncheckedAccount<'info>,
+    /// Required only when the user is blacklisted
+    #[account(mut, address = config.recovery_account @ MetalTokenError::RecoveryAccountNotSet, token::mint = mint)]
+    pub recovery_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct RejectRedemption<'info> {
+    #[account(seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    #[account(mut, has_one = user, has_one = mint)]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: The user who made the request; receives the escrow rent.
+    #[account(mut)]
+    pub user: AccountInfo<'info>,
+    #[account(mut, token::mint = mint, token::authority = user)]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"redemption_escrow", mint.key().as_ref(), user.key().as_ref(), &redemption_request.request_id.to_le_bytes()],
+        bump = redemption_request.escrow_bump,
+    )]
+    pub redemption_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [b"redemption_authority", mint.key().as_ref()], bump, seeds::program = crate::ID)]
+    /// CHECK: PDA that owns every redemption escrow of the mint.
+    pub redemption_authority: AccountInfo<'info>,
+    #[account(
+        seeds = [b"blacklist", mint.key().as_ref(), user.key().as_ref()],
+        bump,
+        seeds::program = config.gatekeeper_program
+    )]
+    /// CHECK: Gatekeeper blacklist marker for the user; may not exist.
+    pub blacklist_entry: UncheckedAccount<'info>,
+    /// Required only when the user is blacklisted
+    #[account(mut, address = config.recovery_account @ MetalTokenError::RecoveryAccountNotSet, token::mint = mint)]
+    pub recovery_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// Required: Role account for supply controller access
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), admin.key().as_ref(), &[Role::SupplyController as u8]],
+        bump
+    )]
+    pub supply_controller_role: Account<'info, UserRole>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRedemptionStatus<'info> {
+    #[account(seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, has_one = mint)]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+    /// Required: Role account for supply controller access
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), admin.key().as_ref(), &[Role::SupplyController as u8]],
+        bump
+    )]
+    pub supply_controller_role: Account<'info, UserRole>,
+}
+
+
+#[derive(Accounts)]
+pub struct WipeAddress<'info> {
+    #[account(mut, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    #[account(mut, address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: The user whose tokens are being wiped.
+    pub target_user: AccountInfo<'info>,
+    #[account(mut, token::mint = mint, token::authority = target_user)]
+    pub target_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [b"blacklist", config.mint.key().as_ref(), target_user.key().as_ref()], 
+        bump, 
+        seeds::program = config.gatekeeper_program
+    )]
+    /// CHECK: The PDA marker account for the blacklist entry.
+    pub blacklist_entry: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"asset_protection", mint.key().as_ref()],
+        bump,
+        seeds::program = crate::ID
+    )]
+    /// CHECK: This is a PDA.
+    pub asset_protection_pda: AccountInfo<'info>,
+    /// Required: Role account for enum-based access
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), admin.key().as_ref(), &[Role::AssetProtector as u8]],
+        bump
+    )]
+    pub asset_protection_role: Account<'info, UserRole>,
+    pub gatekeeper_program: Program<'info, TransferHookGatekeeper>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct SetRecoveryAccount<'info> {
+    #[account(mut, has_one = admin, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(token::mint = mint)]
+    pub recovery_token_account: InterfaceAccount<'info, TokenAccount>,
+}
+
+#[derive(Accounts)]
+pub struct SeizeTokens<'info> {
+    #[account(seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: The user whose tokens are being seized.
+    pub target_user: AccountInfo<'info>,
+    #[account(mut, token::mint = mint, token::authority = target_user)]
+    pub target_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, address = config.recovery_account @ MetalTokenError::RecoveryAccountNotSet, token::mint = mint)]
+    pub recovery_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [b"blacklist", config.mint.key().as_ref(), target_user.key().as_ref()], 
+        bump, 
+        seeds::program = config.gatekeeper_program
+    )]
+    /// CHECK: The PDA marker account for the blacklist entry.
+    pub blacklist_entry: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"asset_protection", mint.key().as_ref()],
+        bump,
+        seeds::program = crate::ID
+    )]
+    /// CHECK: This is a PDA (permanent delegate of the mint).
+    pub asset_protection_pda: AccountInfo<'info>,
+    /// Required: Role account for enum-based access
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), admin.key().as_ref(), &[Role::AssetProtector as u8]],
+        bump
+    )]
+    pub asset_protection_role: Account<'info, UserRole>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+ #[derive(Accounts)]
+ pub struct SetAdmin<'info> {
+    #[account(mut, has_one = admin, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+ }
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(mut, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub pending_admin: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAdminTransfer<'info> {
+    #[account(mut, has_one = admin, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Transfer mint authority from PDA back to a new authority
+#[derive(Accounts)]
+pub struct TransferMintAuthority<'info> {
+    #[account(has_one = admin, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(mut, address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"mint_authority", mint.key().as_ref()],
+        bump,
+        seeds::program = crate::ID
+    )]
+    /// CHECK: Current mint authority PDA
+    pub mint_authority_pda: AccountInfo<'info>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+#[instruction(action: GovernanceAction)]
+pub struct QueueAction<'info> {
+    #[account(mut, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32 + 8 + 1 + 32 + 1 + 8 + 8 + 32 + 8 + 8, // discriminator + mint + id + action (tag + pubkey + role + validity window) + proposer + timestamps
+        seeds = [b"queued_action", mint.key().as_ref(), &config.governance_action_counter.checked_add(1).unwrap().to_le_bytes()],
+        bump
+    )]
+    pub queued_action: Account<'info, QueuedAction>,
+    /// Authority's grant of the admin role for the role being granted
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), authority.key().as_ref(), &[config.role_admins[action.administered_role() as usize] as u8]],
+        bump
+    )]
+    pub admin_role: Option<Account<'info, UserRole>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAction<'info> {
+    #[account(seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub authority: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, close = proposer, has_one = mint, has_one = proposer)]
+    pub queued_action: Account<'info, QueuedAction>,
+    /// CHECK: Receives the rent of the cancelled action; validated via has_one.
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), authority.key().as_ref(), &[Role::DefaultAdmin as u8]],
+        bump
+    )]
+    pub default_admin_role: Option<Account<'info, UserRole>>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteGovernanceAction<'info> {
+    #[account(mut, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub executor: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, close = proposer, has_one = mint, has_one = proposer)]
+    pub queued_action: Account<'info, QueuedAction>,
+    /// CHECK: Receives the rent of the executed action; validated via has_one.
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteSetMultisigPolicy<'info> {
+    #[account(mut, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub executor: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, close = proposer, has_one = mint, has_one = proposer)]
+    pub queued_action: Account<'info, QueuedAction>,
+    /// CHECK: Receives the rent of the executed action; validated via has_one.
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+    #[account(seeds = [b"role_members", mint.key().as_ref(), &[Role::SupplyController as u8]], bump)]
+    pub supply_controller_members: Option<Account<'info, RoleMembers>>,
+    #[account(seeds = [b"role_members", mint.key().as_ref(), &[Role::AssetProtector as u8]], bump)]
+    pub asset_protector_members: Option<Account<'info, RoleMembers>>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteTransferMintAuthority<'info> {
+    #[account(seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub executor: Signer<'info>,
+    #[account(mut, address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, close = proposer, has_one = mint, has_one = proposer)]
+    pub queued_action: Account<'info, QueuedAction>,
+    /// CHECK: Receives the rent of the executed action; validated via has_one.
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"mint_authority", mint.key().as_ref()],
+        bump,
+        seeds::program = crate::ID
+    )]
+    /// CHECK: Current mint authority PDA
+    pub mint_authority_pda: AccountInfo<'info>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey, role: Role)]
+pub struct ExecuteGrantRole<'info> {
+    #[account(seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, close = proposer, has_one = mint, has_one = proposer)]
+    pub queued_action: Account<'info, QueuedAction>,
+    /// CHECK: Receives the rent of the executed action; validated via has_one.
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+    #[account(
+        init,
+        payer = executor,
+        space = 8 + 32 + 1 + 8 + 8 + 8 + 32, // discriminator + pubkey + role enum + mint allowance + validity window + granted_by
+        seeds = [b"user_role", mint.key().as_ref(), user.as_ref(), &[role as u8]],
+        bump
+    )]
+    pub user_role: Account<'info, UserRole>,
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = ROLE_MEMBERS_SPACE,
+        seeds = [b"role_members", mint.key().as_ref(), &[role as u8]],
+        bump
+    )]
+    pub role_members: Account<'info, RoleMembers>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetMultisigPolicy<'info> {
+    #[account(mut, has_one = admin, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(seeds = [b"role_members", mint.key().as_ref(), &[Role::SupplyController as u8]], bump)]
+    pub supply_controller_members: Option<Account<'info, RoleMembers>>,
+    #[account(seeds = [b"role_members", mint.key().as_ref(), &[Role::AssetProtector as u8]], bump)]
+    pub asset_protector_members: Option<Account<'info, RoleMembers>>,
+}
+
+#[derive(Accounts)]
+#[instruction(action: ProposalAction)]
+pub struct CreateProposal<'info> {
+    #[account(mut, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + 32 + 8 + (1 + 32 + 8 + 32) + 32 + (4 + 32 * MAX_PROPOSAL_APPROVALS) + 8, // discriminator + mint + id + action + proposer + approvals + created_at
+        seeds = [b"proposal", mint.key().as_ref(), &config.proposal_counter.checked_add(1).unwrap().to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), proposer.key().as_ref(), &[action.required_role() as u8]],
+        bump
+    )]
+    pub proposer_role: Account<'info, UserRole>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    pub approver: Signer<'info>,
+    #[account(address = proposal.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, has_one = mint)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), approver.key().as_ref(), &[proposal.action.required_role() as u8]],
+        bump
+    )]
+    pub approver_role: Account<'info, UserRole>,
+}
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account(seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub authority: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, close = proposer, has_one = mint, has_one = proposer)]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: Receives the rent of the cancelled proposal; validated via has_one.
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(recipient: Pubkey, amount: u64, purchase_ref: [u8; 32])]
+pub struct ExecuteMintProposal<'info> {
+    #[account(mut, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    #[account(mut, address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, close = proposer, has_one = mint, has_one = proposer)]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: Receives the rent of the executed proposal; validated via has_one.
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"user_role", mint.key().as_ref(), proposer.key().as_ref(), &[Role::SupplyController as u8]],
+        bump
+    )]
+    pub proposer_role: Account<'info, UserRole>,
+    #[account(seeds = [b"mint_authority", mint.key().as_ref()], bump, seeds::program = crate::ID)]
+    /// CHECK: PDA mint authority.
+    pub mint_authority_pda: AccountInfo<'info>,
+    /// CHECK: Recipient wallet; must match the proposal.
+    #[account(address = recipient)]
+    pub recipient_wallet: AccountInfo<'info>,
+    #[account(
+        init_if_needed,
+        payer = executor,
+        associated_token::mint = mint,
+        associated_token::authority = recipient_wallet,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = executor,
+        space = PURCHASE_RECORD_SPACE,
+        seeds = [b"purchase", mint.key().as_ref(), purchase_ref.as_ref()],
+        bump
+    )]
+    pub purchase_record: Account<'info, PurchaseRecord>,
+    #[account(seeds = [b"reserve", mint.key().as_ref()], bump)]
+    pub reserve_attestation: Account<'info, ReserveAttestation>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(target_user: Pubkey)]
+pub struct ExecuteWipeProposal<'info> {
+    #[account(seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub executor: Signer<'info>,
+    #[account(mut, address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, close = proposer, has_one = mint, has_one = proposer)]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: Receives the rent of the executed proposal; validated via has_one.
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+    #[account(mut, token::mint = mint, token::authority = target_user)]
+    pub target_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [b"blacklist", config.mint.key().as_ref(), target_user.as_ref()],
+        bump,
+        seeds::program = config.gatekeeper_program
+    )]
+    /// CHECK: The PDA marker account for the blacklist entry.
+    pub blacklist_entry: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"asset_protection", mint.key().as_ref()],
+        bump,
+        seeds::program = crate::ID
+    )]
+    /// CHECK: This is a PDA.
+    pub asset_protection_pda: AccountInfo<'info>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+#[instruction(target_user: Pubkey)]
+pub struct ExecuteSeizeProposal<'info> {
+    #[account(seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub executor: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, close = proposer, has_one = mint, has_one = proposer)]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: Receives the rent of the executed proposal; validated via has_one.
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+    #[account(mut, token::mint = mint, token::authority = target_user)]
+    pub target_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, address = config.recovery_account @ MetalTokenError::RecoveryAccountNotSet, token::mint = mint)]
+    pub recovery_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [b"blacklist", config.mint.key().as_ref(), target_user.as_ref()],
+        bump,
+        seeds::program = config.gatekeeper_program
+    )]
+    /// CHECK: The PDA marker account for the blacklist entry.
+    pub blacklist_entry: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"asset_protection", mint.key().as_ref()],
+        bump,
+        seeds::program = crate::ID
+    )]
+    /// CHECK: This is a PDA (permanent delegate of the mint).
+    pub asset_protection_pda: AccountInfo<'info>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateReserveAttestation<'info> {
+    #[account(seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub auditor: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = auditor,
+        space = 8 + 32 + 8 + 8 + 32 + 32 + 8, // discriminator + mint + grams + audited_at + hash + auditor + updated_at
+        seeds = [b"reserve", mint.key().as_ref()],
+        bump
+    )]
+    pub reserve_attestation: Account<'info, ReserveAttestation>,
+    /// Required: Role account for auditor access
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), auditor.key().as_ref(), &[Role::Auditor as u8]],
+        bump
+    )]
+    pub auditor_role: Account<'info, UserRole>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetTransferFee<'info> {
+    #[account(seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub authority: Signer<'info>,
+    #[account(mut, address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(seeds = [b"fee_controller", mint.key().as_ref()], bump, seeds::program = crate::ID)]
+    /// CHECK: PDA holding the transfer fee config authority.
+    pub fee_controller_pda: AccountInfo<'info>,
+    /// Required: Role account for fee controller access
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), authority.key().as_ref(), &[Role::FeeController as u8]],
+        bump
+    )]
+    pub fee_controller_role: Account<'info, UserRole>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct SetFeeTreasury<'info> {
+    #[account(mut, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub authority: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(token::mint = mint)]
+    pub fee_treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// Required: Role account for fee controller access
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), authority.key().as_ref(), &[Role::FeeController as u8]],
+        bump
+    )]
+    pub fee_controller_role: Account<'info, UserRole>,
+}
+
+#[derive(Accounts)]
+pub struct SetRedemptionFee<'info> {
+    #[account(mut, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub authority: Signer<'info>,
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// Required: Role account for fee controller access
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), authority.key().as_ref(), &[Role::FeeController as u8]],
+        bump
+    )]
+    pub fee_controller_role: Account<'info, UserRole>,
+}
+
+#[derive(Accounts)]
+pub struct HarvestWithheldFees<'info> {
+    #[account(seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub authority: Signer<'info>,
+    #[account(mut, address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// Required: Role account for fee controller access
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), authority.key().as_ref(), &[Role::FeeController as u8]],
+        bump
+    )]
+    pub fee_controller_role: Account<'info, UserRole>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawWithheldFees<'info> {
+    #[account(seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub authority: Signer<'info>,
+    #[account(mut, address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, address = config.fee_treasury @ MetalTokenError::FeeTreasuryNotSet, token::mint = mint)]
+    pub fee_treasury: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [b"fee_controller", mint.key().as_ref()], bump, seeds::program = crate::ID)]
+    /// CHECK: PDA holding the withdraw withheld authority.
+    pub fee_controller_pda: AccountInfo<'info>,
+    /// Required: Role account for fee controller access
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), authority.key().as_ref(), &[Role::FeeController as u8]],
+        bump
+    )]
+    pub fee_controller_role: Account<'info, UserRole>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    SupplyController,
+    AssetProtector,
+    FeeController,
+    DefaultAdmin,
+    Auditor,
+    ComplianceOfficer,
+}
+
+/// Every role is administered by DefaultAdmin unless reconfigured
+impl Default for Role {
+    fn default() -> Self {
+        Role::DefaultAdmin
+    }
+}
+
+#[account]
+pub struct UserRole {
+    pub user: Pubkey,
+    pub role: Role,
+    /// Remaining amount a SupplyController may mint; replenished by DefaultAdmin
+    pub mint_allowance: u64,
+    /// Grant is inactive before this timestamp
+    pub valid_from: i64,
+    /// Grant is inactive from this timestamp on; 0 means no expiry
+    pub valid_until: i64,
+    pub granted_by: Pubkey,
+}
+
+impl UserRole {
+    /// True if this grant is held by `user` for `role` and is within its validity window
+    pub fn is_active(&self, user: Pubkey, role: Role) -> bool {
+        let now = match Clock::get() {
+            Ok(clock) => clock.unix_timestamp,
+            Err(_) => return false,
+        };
+        self.user == user
+            && self.role == role
+            && now >= self.valid_from
+            && (self.valid_until == 0 || now < self.valid_until)
+    }
+}
+
+/// discriminator + mint + role + member count + members
+pub const ROLE_MEMBERS_SPACE: usize = 8 + 32 + 1 + 4 + 4 + 32 * MAX_ROLE_MEMBERS;
+
+/// Holders of one role, seeded by `[b"role_members", mint, role]`
+#[account]
+pub struct RoleMembers {
+    pub mint: Pubkey,
+    pub role: Role,
+    pub member_count: u32,
+    pub members: Vec<Pubkey>,
+}
+
+impl RoleMembers {
+    fn add(&mut self, user: Pubkey) -> Result<()> {
+        if self.members.contains(&user) {
+            return Ok(());
+        }
+        require!(self.members.len() < MAX_ROLE_MEMBERS, MetalTokenError::RoleMembersFull);
+        self.members.push(user);
+        self.member_count = self.members.len() as u32;
+        Ok(())
+    }
+
+    fn remove(&mut self, user: Pubkey) {
+        self.members.retain(|member| *member != user);
+        self.member_count = self.members.len() as u32;
+    }
+}
+
+/// Per-mint configuration, seeded by `[b"config", mint]`
+#[account]
+#[derive(Default)]
+pub struct Config {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub mint: Pubkey,
+    pub gatekeeper_program: Pubkey,
+    pub redemption_request_counter: u64,
+    pub pause_state: PauseState,
+    pub fee_treasury: Pubkey,
+    pub recovery_account: Pubkey,
+    pub redemption_fee: RedemptionFee,
+    /// Seconds a pending redemption may wait before anyone can expire it
+    pub redemption_sla: i64,
+    pub timelock_delay: i64,
+    pub governance_action_counter: u64,
+    /// Maximum minted per Solana epoch across all minters; 0 disables the cap
+    pub epoch_mint_cap: u64,
+    pub mint_epoch: u64,
+    pub minted_in_epoch: u64,
+    /// Admin role of each role, indexed by `Role as usize`
+    pub role_admins: [Role; ROLE_COUNT],
+    /// Approvals required on mint, wipe and seizure proposals; 0 or 1 keeps single-signer operation
+    pub multisig_threshold: u8,
+    /// Mints above this amount must go through a proposal once multisig is enabled
+    pub large_mint_threshold: u64,
+    pub proposal_counter: u64,
+}
+
+/// Fee charged on redemption, snapshotted onto each request
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct RedemptionFee {
+    pub fixed_fee: u64,
+    pub fee_basis_points: u16,
+}
+
+/// Independent pause flags per operation; `transfer` is mirrored into the gatekeeper
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct PauseState {
+    pub mint: bool,
+    pub redeem_request: bool,
+    pub redeem_fulfill: bool,
+    pub transfer: bool,
+    pub wipe: bool,
+}
+
+/// Privileged action awaiting its timelock, seeded by `[b"queued_action", mint, action_id]`
+#[account]
+pub struct QueuedAction {
+    pub mint: Pubkey,
+    pub action_id: u64,
+    pub action: GovernanceAction,
+    pub proposer: Pubkey,
+    pub queued_at: i64,
+    pub eta: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GovernanceAction {
+    TransferMintAuthority { new_authority: Pubkey },
+    SetAdmin { new_admin: Pubkey },
+    GrantRole { user: Pubkey, role: Role, valid_from: i64, valid_until: i64 },
+    SetTimelockDelay { delay: i64 },
+    SetRoleAdmin { role: Role, admin_role: Role },
+    SetMultisigPolicy { threshold: u8, large_mint_threshold: u64 },
+}
+
+impl GovernanceAction {
+    /// Role whose admin may queue this action; only role grants are delegated
+    pub fn administered_role(&self) -> Role {
+        match self {
+            GovernanceAction::GrantRole { role, .. } => *role,
+            _ => Role::DefaultAdmin,
+        }
+    }
+}
+
+/// Mint, wipe or seizure awaiting multisig approval, seeded by `[b"proposal", mint, proposal_id]`
+#[account]
+pub struct Proposal {
+    pub mint: Pubkey,
+    pub proposal_id: u64,
+    pub action: ProposalAction,
+    pub proposer: Pubkey,
+    pub approvals: Vec<Pubkey>,
+    pub created_at: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalAction {
+    Mint { recipient: Pubkey, amount: u64, purchase_ref: [u8; 32] },
+    Wipe { target_user: Pubkey, amount: u64 },
+    Seize { target_user: Pubkey, amount: u64, legal_reference_hash: [u8; 32] },
+}
+
+impl ProposalAction {
+    /// Role that may propose and approve this action
+    pub fn required_role(&self) -> Role {
+        match self {
+            ProposalAction::Mint { .. } => Role::SupplyController,
+            ProposalAction::Wipe { .. } | ProposalAction::Seize { .. } => Role::AssetProtector,
+        }
+    }
+}
+
+/// Marks a purchase reference as settled, seeded by `[b"purchase", mint, purchase_ref]`
+#[account]
+pub struct PurchaseRecord {
+    pub mint: Pubkey,
+    pub purchase_ref: [u8; 32],
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub minted_at: i64,
+}
+
+/// Audited proof-of-reserve, seeded by `[b"reserve", mint]`
+#[account]
+pub struct ReserveAttestation {
+    pub mint: Pubkey,
+    pub reserve_grams: u64,
+    pub audited_at: i64,
+    pub document_hash: [u8; 32],
+    pub auditor: Pubkey,
+    pub updated_at: i64,
+}
+
+#[account]
+pub struct RedemptionRequest {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub status: RedemptionStatus,
+    pub requested_at: i64,
+    /// SLA deadline after which a pending request can be expired by anyone
+    pub expires_at: i64,
+    pub completed_at: i64,
+    pub rejection_reason: u8,
+    pub delivery: DeliveryDetails,
+    pub product_mix: Vec<ProductQuantity>,
+    /// Redemption fee snapshotted when the request was made
+    pub fee_amount: u64,
+    pub request_id: u64,
+    pub escrow_bump: u8,
+}
+
+/// Redemption limits and deliverable products, seeded by `[b"redemption_policy", mint]`
+#[account]
+pub struct RedemptionPolicy {
+    pub mint: Pubkey,
+    pub min_amount: u64,
+    pub step_amount: u64,
+    pub products: Vec<PhysicalProduct>,
+}
+
+/// A bar or coin that can be delivered, e.g. a 1 oz coin or a 100 g bar
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PhysicalProduct {
+    pub product_id: u16,
+    /// Token base units needed for one unit of the product
+    pub amount: u64,
+}
+
+/// One recipient of a `batch_mint`, tagged with the off-chain purchase request id
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BatchMintEntry {
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub purchase_ref: [u8; 32],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ProductQuantity {
+    pub product_id: u16,
+    pub quantity: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RedemptionMethod {
+    VaultPickup,
+    CourierDelivery,
+    CashOut,
+}
+
+/// How the redeemed metal leaves custody
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DeliveryDetails {
+    pub method: RedemptionMethod,
+    /// Destination vault or pickup location id (zero for cash-out)
+    pub destination_id: [u8; 32],
+    /// Hash of the encrypted shipping details held off-chain
+    pub shipping_details_hash: [u8; 32],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum RedemptionStatus {
+    Pending,
+    Processing,
+    Fulfilled,
+    Cancelled,
+    Rejected,
+    Expired,
+}
+
+#[event]
+pub struct TokenInitialized {
+    pub mint: Pubkey,
+    pub admin: Pubkey,
+    pub gatekeeper_program: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[event]
+pub struct RoleUpdated {
+    pub mint: Pubkey,
+    pub role: String,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct PauseStateUpdated {
+    pub mint: Pubkey,
+    pub old_state: PauseState,
+    pub new_state: PauseState,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct TokensMinted {
+    pub mint: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub authority: Pubkey,
+    pub recipient: Pubkey,
+    pub purchase_ref: [u8; 32],
+}
+
+#[event]
+pub struct MinterAllowanceUpdated {
+    pub mint: Pubkey,
+    pub minter: Pubkey,
+    pub old_allowance: u64,
+    pub new_allowance: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct EpochMintCapUpdated {
+    pub mint: Pubkey,
+    pub old_cap: u64,
+    pub new_cap: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct BatchMintSettled {
+    pub mint: Pubkey,
+    pub to: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub purchase_ref: [u8; 32],
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ReserveAttested {
+    pub mint: Pubkey,
+    pub reserve_grams: u64,
+    pub audited_at: i64,
+    pub document_hash: [u8; 32],
+    pub auditor: Pubkey,
+    pub supply: u64,
+}
+
+#[event]
+pub struct RedemptionRequested {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub request_id: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub expires_at: i64,
+    pub delivery: DeliveryDetails,
+    pub product_mix: Vec<ProductQuantity>,
+    pub fee_amount: u64,
+}
+
+#[event]
+pub struct RedemptionPolicyUpdated {
+    pub mint: Pubkey,
+    pub min_amount: u64,
+    pub step_amount: u64,
+    pub products: Vec<PhysicalProduct>,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct RedemptionFulfilled {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub request_id: u64,
+    pub amount: u64,
+    pub fee_amount: u64,
+    pub burned_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RedemptionCancelled {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub request_id: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub cancelled_by: Pubkey,
+}
+
+#[event]
+pub struct RedemptionRejected {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub request_id: u64,
+    pub amount: u64,
+    pub old_status: RedemptionStatus,
+    pub reason_code: u8,
+    pub timestamp: i64,
+    pub rejected_by: Pubkey,
+}
+
+#[event]
+pub struct RedemptionExpired {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub request_id: u64,
+    pub amount: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+    pub cranked_by: Pubkey,
+}
+
+#[event]
+pub struct RedemptionSlaUpdated {
+    pub mint: Pubkey,
+    pub old_sla: i64,
+    pub new_sla: i64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct RedemptionStatusUpdated {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub request_id: u64,
+    pub old_status: RedemptionStatus,
+    pub new_status: RedemptionStatus,
+}
+
+#[event]
+pub struct TokensWiped {
+    pub mint: Pubkey,
+    pub target_user: Pubkey,
+    pub amount: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct TokensSeized {
+    pub mint: Pubkey,
+    pub target_user: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub legal_reference_hash: [u8; 32],
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct RecoveryAccountUpdated {
+    pub mint: Pubkey,
+    pub old_account: Pubkey,
+    pub new_account: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct WithheldTokensWithdrawn {
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct WithheldTokensHarvested {
+    pub mint: Pubkey,
+    pub source_count: u32,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct FeeRateUpdated {
+    pub mint: Pubkey,
+    pub old_rate: u16,
+    pub new_rate: u16,
+    pub maximum_fee: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct RedemptionFeeUpdated {
+    pub mint: Pubkey,
+    pub old_fee: RedemptionFee,
+    pub new_fee: RedemptionFee,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct FeeWalletUpdated {
+    pub mint: Pubkey,
+    pub old_wallet: Pubkey,
+    pub new_wallet: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct RoleAssigned {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub role: String,
+    pub authority: Pubkey,
+    pub valid_from: i64,
+    pub valid_until: i64,
+}
+
+#[event]
+pub struct RoleAdminChanged {
+    pub mint: Pubkey,
+    pub role: Role,
+    pub previous_admin_role: Role,
+    pub new_admin_role: Role,
+}
+
+#[event]
+pub struct RoleRemoved {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub role: String,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferProposed {
+    pub mint: Pubkey,
+    pub current_admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferAccepted {
+    pub mint: Pubkey,
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferCancelled {
+    pub mint: Pubkey,
+    pub admin: Pubkey,
+    pub cancelled_admin: Pubkey,
+}
+
+#[event]
+pub struct ActionQueued {
+    pub mint: Pubkey,
+    pub action_id: u64,
+    pub action: GovernanceAction,
+    pub proposer: Pubkey,
+    pub eta: i64,
+}
+
+#[event]
+pub struct ActionCancelled {
+    pub mint: Pubkey,
+    pub action_id: u64,
+    pub cancelled_by: Pubkey,
+}
+
+#[event]
+pub struct MultisigPolicyUpdated {
+    pub mint: Pubkey,
+    pub threshold: u8,
+    pub large_mint_threshold: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub mint: Pubkey,
+    pub proposal_id: u64,
+    pub action: ProposalAction,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct ProposalApproved {
+    pub mint: Pubkey,
+    pub proposal_id: u64,
+    pub approver: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct ProposalCancelled {
+    pub mint: Pubkey,
+    pub proposal_id: u64,
+    pub cancelled_by: Pubkey,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub mint: Pubkey,
+    pub proposal_id: u64,
+    pub executed_by: Pubkey,
+}
+
+#[event]
+pub struct ActionExecuted {
+    pub mint: Pubkey,
+    pub action_id: u64,
+    pub executed_by: Pubkey,
+}
+
+#[event]
+pub struct TimelockDelayUpdated {
+    pub mint: Pubkey,
+    pub old_delay: i64,
+    pub new_delay: i64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub mint: Pubkey,
+    pub authority_type: String,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub transferred_by: Pubkey,
+}
+
+
+#[error_code]
+pub enum MetalTokenError {
+    #[msg("Invalid amount.")]
+    InvalidAmount,
+    #[msg("Contract is paused.")]
+    ContractPaused,
+    #[msg("Invalid redemption request status for this action.")]
+    InvalidRequestStatus,
+    #[msg("Address is not on the blacklist.")]
+    AddressNotBlacklisted,
+    #[msg("Counter overflow.")]
+    CounterOverflow,
+    #[msg("Insufficient token balance.")]
+    InsufficientBalance,
+    #[msg("Unauthorized access.")]
+    Unauthorized,
+    #[msg("Insufficient available tokens (some may be delegated for redemption).")]
+    InsufficientAvailableTokens,
+    #[msg("Fee basis points exceed the maximum.")]
+    InvalidFeeBasisPoints,
+    #[msg("Fee treasury is not set or does not match the configured account.")]
+    FeeTreasuryNotSet,
+    #[msg("Mint would exceed the attested reserve.")]
+    ExceedsAttestedReserve,
+    #[msg("Invalid audit timestamp.")]
+    InvalidAuditTimestamp,
+    #[msg("Invalid admin address.")]
+    InvalidAdmin,
+    #[msg("No admin transfer is pending.")]
+    NoPendingAdmin,
+    #[msg("A timelock is configured; queue this action instead.")]
+    TimelockRequired,
+    #[msg("Timelock delay has not elapsed.")]
+    TimelockNotElapsed,
+    #[msg("Invalid timelock delay.")]
+    InvalidTimelockDelay,
+    #[msg("Queued action does not match this instruction.")]
+    ActionMismatch,
+    #[msg("A legal reference hash is required.")]
+    MissingLegalReference,
+    #[msg("Recovery account is not set or does not match the configured account.")]
+    RecoveryAccountNotSet,
+    #[msg("Delivery details are incomplete for the chosen redemption method.")]
+    InvalidDeliveryDetails,
+    #[msg("Too many products.")]
+    TooManyProducts,
+    #[msg("Amount is below the minimum redemption.")]
+    BelowMinimumRedemption,
+    #[msg("Amount cannot be delivered as whole products.")]
+    InvalidRedemptionDenomination,
+    #[msg("Product mix does not match the redemption policy.")]
+    InvalidProductMix,
+    #[msg("Redemption fee exceeds the redeemed amount.")]
+    FeeExceedsAmount,
+    #[msg("Invalid redemption SLA.")]
+    InvalidRedemptionSla,
+    #[msg("Redemption request has not reached its SLA deadline.")]
+    RedemptionNotExpired,
+    #[msg("Batch is empty or too large.")]
+    InvalidBatchSize,
+    #[msg("Remaining accounts do not match the batch entries.")]
+    BatchAccountsMismatch,
+    #[msg("Recipient token account does not match the mint or recipient.")]
+    InvalidRecipientAccount,
+    #[msg("Purchase reference has already been settled.")]
+    DuplicatePurchaseReference,
+    #[msg("Purchase record account does not match the purchase reference.")]
+    InvalidPurchaseRecord,
+    #[msg("Mint exceeds the minter's remaining allowance.")]
+    MintAllowanceExceeded,
+    #[msg("Mint exceeds the per-epoch mint cap.")]
+    EpochMintCapExceeded,
+    #[msg("Role validity window is invalid.")]
+    InvalidRoleValidity,
+    #[msg("Role already has the maximum number of members; revoke one before granting another.")]
+    RoleMembersFull,
+    #[msg("Role member index does not belong to the requested role.")]
+    RoleMismatch,
+    #[msg("This operation requires a multisig proposal.")]
+    ProposalRequired,
+    #[msg("Invalid multisig threshold.")]
+    InvalidMultisigThreshold,
+    #[msg("Signer has already approved this proposal.")]
+    AlreadyApproved,
+    #[msg("Proposal has not reached the approval threshold.")]
+    ThresholdNotMet,
+    #[msg("Approver role accounts do not match the proposal's approvals.")]
+    ApproverRolesMismatch,
+}
\ No newline at end of file
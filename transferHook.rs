@@ -1,6 +1,6 @@
 use anchor_lang::{
     prelude::*,
-    system_program::{create_account, CreateAccount},
+    system_program::{create_account, transfer, CreateAccount, Transfer},
 };
 use anchor_spl::token_interface::{Mint, TokenAccount};
 use spl_tlv_account_resolution::{
@@ -10,6 +10,19 @@ use spl_transfer_hook_interface::instruction::{ExecuteInstruction, TransferHookI
 
 declare_id!("HPpSduHvXR6U26ZWPy9DRuASMzGnqis8EPKNxiHHbWJY");
 
+/// Number of KYC verification tiers with their own limits
+pub const MAX_KYC_TIERS: usize = 4;
+/// Maximum number of ISO 3166-1 alpha-2 country codes that can be blocked per mint
+pub const MAX_BLOCKED_JURISDICTIONS: usize = 16;
+/// Number of `Role` variants
+pub const ROLE_COUNT: usize = 6;
+/// discriminator + admin + pending admin + pause authority + seizure authority + redemption authority + role registry
+/// + pause flag + compliance mode + tier limits + blocked jurisdictions + role admins
+pub const CONFIG_SPACE: usize =
+    8 + 32 + 32 + 32 + 32 + 32 + 32 + 1 + 1 + 16 * MAX_KYC_TIERS + 4 + 2 * MAX_BLOCKED_JURISDICTIONS + ROLE_COUNT;
+/// discriminator + admin; the config layout before `migrate_config`
+pub const LEGACY_CONFIG_SPACE: usize = 8 + 32;
+
 #[program]
 pub mod transfer_hook_gatekeeper {
     use super::*;
@@ -18,28 +31,7 @@ pub mod transfer_hook_gatekeeper {
     pub fn initialize_extra_account_meta_list(
         ctx: Context<InitializeExtraAccountMetaList>,
     ) -> Result<()> {
-        let account_metas = vec![
-            // index 5: source blacklist PDA
-            ExtraAccountMeta::new_with_seeds(
-                &[
-                    Seed::Literal { bytes: "blacklist".as_bytes().to_vec() },
-                    Seed::AccountKey { index: 1 }, // The mint account
-                    Seed::AccountKey { index: 3 }, // source token account owner
-                ],
-                false, // is_signer
-                false, // is_writable
-            )?,
-            // index 6: destination blacklist PDA
-            ExtraAccountMeta::new_with_seeds(
-                &[
-                    Seed::Literal { bytes: "blacklist".as_bytes().to_vec() },
-                    Seed::AccountKey { index: 1 }, // The mint account
-                    Seed::AccountKey { index: 2 }, // destination token account owner
-                ],
-                false, // is_signer
-                false, // is_writable
-            )?,
-        ];
+        let account_metas = extra_account_metas()?;
 
         let account_size = ExtraAccountMetaList::size_of(account_metas.len())? as u64;
         let lamports = Rent::get()?.minimum_balance(account_size as usize);
@@ -73,37 +65,393 @@ pub mod transfer_hook_gatekeeper {
         Ok(())
     }
 
+    /// Rewrite an existing mint's extra account meta list to the current layout (admin only).
+    /// Mints created before the gatekeeper config was extended must run `migrate_config` first.
+    pub fn update_extra_account_meta_list(ctx: Context<UpdateExtraAccountMetaList>) -> Result<()> {
+        let account_metas = extra_account_metas()?;
+        let account_size = ExtraAccountMetaList::size_of(account_metas.len())?;
+
+        let meta_list = ctx.accounts.extra_account_meta_list.to_account_info();
+        realloc_with_rent(
+            &meta_list,
+            &ctx.accounts.admin.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            account_size,
+        )?;
+        ExtraAccountMetaList::update::<ExecuteInstruction>(
+            &mut meta_list.try_borrow_mut_data()?,
+            &account_metas,
+        )?;
+
+        emit!(ExtraAccountMetaListUpdated {
+            mint: ctx.accounts.mint.key(),
+            extra_accounts: account_metas.len() as u8,
+            authority: ctx.accounts.admin.key(),
+        });
+        Ok(())
+    }
+
     /// Initialize the gatekeeper configuration
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        pause_authority: Pubkey,
+        seizure_authority: Pubkey,
+        redemption_authority: Pubkey,
+        role_registry: Pubkey,
+    ) -> Result<()> {
         ctx.accounts.config.admin = ctx.accounts.admin.key();
+        ctx.accounts.config.pending_admin = Pubkey::default();
+        ctx.accounts.config.pause_authority = pause_authority;
+        ctx.accounts.config.seizure_authority = seizure_authority;
+        ctx.accounts.config.redemption_authority = redemption_authority;
+        ctx.accounts.config.role_registry = role_registry;
+        ctx.accounts.config.is_paused = false;
+        ctx.accounts.config.compliance_mode = ComplianceMode::Denylist;
+        ctx.accounts.config.tier_limits = [TierLimit::default(); MAX_KYC_TIERS];
+        ctx.accounts.config.blocked_jurisdictions = Vec::new();
+        ctx.accounts.config.role_admins = [Role::DefaultAdmin; ROLE_COUNT];
+        Ok(())
+    }
+
+    /// Upgrade a config that only holds the admin to the current layout (admin only).
+    /// The new fields get the same defaults as `initialize`.
+    pub fn migrate_config(
+        ctx: Context<MigrateConfig>,
+        pause_authority: Pubkey,
+        seizure_authority: Pubkey,
+        redemption_authority: Pubkey,
+        role_registry: Pubkey,
+    ) -> Result<()> {
+        let config_info = ctx.accounts.config.to_account_info();
+        require!(
+            config_info.data_len() == LEGACY_CONFIG_SPACE,
+            GatekeeperError::ConfigAlreadyMigrated
+        );
+        let admin = Pubkey::try_from(&config_info.try_borrow_data()?[8..LEGACY_CONFIG_SPACE])
+            .map_err(|_| GatekeeperError::InvalidAdmin)?;
+        require!(ctx.accounts.admin.key() == admin, GatekeeperError::Unauthorized);
+
+        realloc_with_rent(
+            &config_info,
+            &ctx.accounts.admin.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            CONFIG_SPACE,
+        )?;
+        let config = Config {
+            admin,
+            pending_admin: Pubkey::default(),
+            pause_authority,
+            seizure_authority,
+            redemption_authority,
+            role_registry,
+            is_paused: false,
+            compliance_mode: ComplianceMode::Denylist,
+            tier_limits: [TierLimit::default(); MAX_KYC_TIERS],
+            blocked_jurisdictions: Vec::new(),
+            role_admins: [Role::DefaultAdmin; ROLE_COUNT],
+        };
+        config.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+
+        emit!(ConfigMigrated {
+            mint: ctx.accounts.mint.key(),
+            authority: admin,
+        });
+        Ok(())
+    }
+
+    /// Cap transfer size and holdings for a KYC tier (admin only)
+    pub fn set_tier_limit(
+        ctx: Context<SetComplianceRules>,
+        tier: u8,
+        max_transfer_amount: u64,
+        max_holding_amount: u64,
+    ) -> Result<()> {
+        require!((tier as usize) < MAX_KYC_TIERS, GatekeeperError::InvalidKycTier);
+        ctx.accounts.config.tier_limits[tier as usize] = TierLimit {
+            max_transfer_amount,
+            max_holding_amount,
+        };
+
+        emit!(TierLimitUpdated {
+            mint: ctx.accounts.mint.key(),
+            tier,
+            max_transfer_amount,
+            max_holding_amount,
+            authority: ctx.accounts.admin.key(),
+        });
+        Ok(())
+    }
+
+    /// Replace the list of blocked jurisdictions (admin only)
+    pub fn set_blocked_jurisdictions(
+        ctx: Context<SetComplianceRules>,
+        jurisdictions: Vec<[u8; 2]>,
+    ) -> Result<()> {
+        require!(
+            jurisdictions.len() <= MAX_BLOCKED_JURISDICTIONS,
+            GatekeeperError::TooManyJurisdictions
+        );
+        ctx.accounts.config.blocked_jurisdictions = jurisdictions.clone();
+
+        emit!(BlockedJurisdictionsUpdated {
+            mint: ctx.accounts.mint.key(),
+            jurisdictions,
+            authority: ctx.accounts.admin.key(),
+        });
+        Ok(())
+    }
+
+    /// Switch between denylist, allowlist (KYC) or combined enforcement (admin only)
+    pub fn set_compliance_mode(ctx: Context<SetComplianceMode>, mode: ComplianceMode) -> Result<()> {
+        let old_mode = ctx.accounts.config.compliance_mode;
+        ctx.accounts.config.compliance_mode = mode;
+
+        emit!(ComplianceModeUpdated {
+            mint: ctx.accounts.mint.key(),
+            old_mode,
+            new_mode: mode,
+            authority: ctx.accounts.admin.key(),
+        });
+        Ok(())
+    }
+
+    /// Mark a wallet as KYC-verified for this mint
+    pub fn add_kyc_entry(
+        ctx: Context<AddKycEntry>,
+        tier: u8,
+        country_code: [u8; 2],
+        expires_at: i64,
+    ) -> Result<()> {
+        // Check if user has ComplianceOfficer role
+        let signer_key = ctx.accounts.authority.key();
+        let is_main_admin = signer_key == ctx.accounts.config.admin;
+        let has_enum_role = ctx.accounts.compliance_role
+            .as_ref()
+            .map(|role_account| role_account.is_active(signer_key, Role::ComplianceOfficer))
+            .unwrap_or(false);
+        let has_registry_grant = has_registry_role(
+            &ctx.accounts.registry_role,
+            &ctx.accounts.config,
+            ctx.accounts.mint.key(),
+            signer_key,
+            Role::ComplianceOfficer,
+        );
+        require!(
+            has_enum_role || has_registry_grant || is_main_admin,
+            GatekeeperError::Unauthorized
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        require!((tier as usize) < MAX_KYC_TIERS, GatekeeperError::InvalidKycTier);
+        require!(expires_at > now, GatekeeperError::KycExpired);
+
+        let kyc_entry = &mut ctx.accounts.kyc_entry;
+        kyc_entry.owner = ctx.accounts.target_address.key();
+        kyc_entry.verified_at = now;
+        kyc_entry.expires_at = expires_at;
+        kyc_entry.tier = tier;
+        kyc_entry.country_code = country_code;
+
+        emit!(KycEntryAdded {
+            mint: ctx.accounts.mint.key(),
+            owner: kyc_entry.owner,
+            tier,
+            country_code,
+            expires_at,
+            authority: signer_key,
+        });
+        Ok(())
+    }
+
+    /// Re-verify a wallet, replacing its tier, jurisdiction and expiry
+    pub fn update_kyc_entry(
+        ctx: Context<UpdateKycEntry>,
+        tier: u8,
+        country_code: [u8; 2],
+        expires_at: i64,
+    ) -> Result<()> {
+        // Check if user has ComplianceOfficer role
+        let signer_key = ctx.accounts.authority.key();
+        let is_main_admin = signer_key == ctx.accounts.config.admin;
+        let has_enum_role = ctx.accounts.compliance_role
+            .as_ref()
+            .map(|role_account| role_account.is_active(signer_key, Role::ComplianceOfficer))
+            .unwrap_or(false);
+        let has_registry_grant = has_registry_role(
+            &ctx.accounts.registry_role,
+            &ctx.accounts.config,
+            ctx.accounts.mint.key(),
+            signer_key,
+            Role::ComplianceOfficer,
+        );
+        require!(
+            has_enum_role || has_registry_grant || is_main_admin,
+            GatekeeperError::Unauthorized
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        require!((tier as usize) < MAX_KYC_TIERS, GatekeeperError::InvalidKycTier);
+        require!(expires_at > now, GatekeeperError::KycExpired);
+
+        let kyc_entry = &mut ctx.accounts.kyc_entry;
+        kyc_entry.verified_at = now;
+        kyc_entry.expires_at = expires_at;
+        kyc_entry.tier = tier;
+        kyc_entry.country_code = country_code;
+
+        emit!(KycEntryUpdated {
+            mint: ctx.accounts.mint.key(),
+            owner: kyc_entry.owner,
+            tier,
+            country_code,
+            expires_at,
+            authority: signer_key,
+        });
+        Ok(())
+    }
+
+    /// Revoke a wallet's KYC verification for this mint
+    pub fn remove_kyc_entry(ctx: Context<RemoveKycEntry>) -> Result<()> {
+        // Check if user has ComplianceOfficer role
+        let signer_key = ctx.accounts.authority.key();
+        let is_main_admin = signer_key == ctx.accounts.config.admin;
+        let has_enum_role = ctx.accounts.compliance_role
+            .as_ref()
+            .map(|role_account| role_account.is_active(signer_key, Role::ComplianceOfficer))
+            .unwrap_or(false);
+        let has_registry_grant = has_registry_role(
+            &ctx.accounts.registry_role,
+            &ctx.accounts.config,
+            ctx.accounts.mint.key(),
+            signer_key,
+            Role::ComplianceOfficer,
+        );
+        require!(
+            has_enum_role || has_registry_grant || is_main_admin,
+            GatekeeperError::Unauthorized
+        );
+
+        // The KYC entry account is closed by the #[account(close)] constraint
+        emit!(KycEntryRemoved {
+            mint: ctx.accounts.mint.key(),
+            owner: ctx.accounts.target_address.key(),
+            authority: signer_key,
+        });
+        Ok(())
+    }
+
+    /// Pause or resume all transfers of the mint (admin or the token program's pause authority)
+    pub fn set_pause_state(ctx: Context<SetPauseState>, is_paused: bool) -> Result<()> {
+        let signer_key = ctx.accounts.authority.key();
+        let config = &mut ctx.accounts.config;
+        require!(
+            signer_key == config.admin || signer_key == config.pause_authority,
+            GatekeeperError::Unauthorized
+        );
+
+        config.is_paused = is_paused;
+
+        emit!(TransferPauseUpdated {
+            mint: ctx.accounts.mint.key(),
+            is_paused,
+            authority: signer_key,
+        });
         Ok(())
     }
     
 
 
+    /// Propose a new admin; the handover completes only when the proposed key calls `accept_admin`
     pub fn set_admin(ctx: Context<SetAdmin>, admin: Pubkey) -> Result<()> {
-        ctx.accounts.config.admin = admin;
+        require!(admin != Pubkey::default(), GatekeeperError::InvalidAdmin);
+        ctx.accounts.config.pending_admin = admin;
+        emit!(AdminTransferProposed {
+            mint: ctx.accounts.mint.key(),
+            current_admin: ctx.accounts.admin.key(),
+            pending_admin: admin,
+        });
+        Ok(())
+    }
+
+    /// Complete a pending admin handover (must be signed by the pending admin)
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(config.pending_admin != Pubkey::default(), GatekeeperError::NoPendingAdmin);
+        require_keys_eq!(
+            config.pending_admin,
+            ctx.accounts.pending_admin.key(),
+            GatekeeperError::Unauthorized
+        );
+
+        let old_admin = config.admin;
+        config.admin = config.pending_admin;
+        config.pending_admin = Pubkey::default();
+
+        emit!(AdminTransferAccepted {
+            mint: ctx.accounts.mint.key(),
+            old_admin,
+            new_admin: config.admin,
+        });
+        Ok(())
+    }
+
+    /// Withdraw a pending admin handover (current admin only)
+    pub fn cancel_admin_transfer(ctx: Context<CancelAdminTransfer>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(config.pending_admin != Pubkey::default(), GatekeeperError::NoPendingAdmin);
+
+        let cancelled_admin = config.pending_admin;
+        config.pending_admin = Pubkey::default();
+
+        emit!(AdminTransferCancelled {
+            mint: ctx.accounts.mint.key(),
+            admin: config.admin,
+            cancelled_admin,
+        });
         Ok(())
     }
     
     /// Add an address to the blacklist
-    pub fn add_to_blacklist(ctx: Context<AddToBlacklist>) -> Result<()> {
+    pub fn add_to_blacklist(
+        ctx: Context<AddToBlacklist>,
+        reason_code: u8,
+        reference_hash: [u8; 32],
+    ) -> Result<()> {
         // Check if user has AssetProtector role
         let signer_key = ctx.accounts.authority.key();
         let is_main_admin = signer_key == ctx.accounts.config.admin;
         let has_enum_role = ctx.accounts.asset_protection_role
             .as_ref()
-            .map(|role_account| role_account.user == signer_key && role_account.role == Role::AssetProtector)
+            .map(|role_account| role_account.is_active(signer_key, Role::AssetProtector))
             .unwrap_or(false);
-            
+        let has_registry_grant = has_registry_role(
+            &ctx.accounts.registry_role,
+            &ctx.accounts.config,
+            ctx.accounts.mint.key(),
+            signer_key,
+            Role::AssetProtector,
+        );
         require!(
-            has_enum_role || is_main_admin,
+            has_enum_role || has_registry_grant || is_main_admin,
             GatekeeperError::Unauthorized
         );
         
         // The blacklist entry account is created by the #[account(init)] constraint
-        // We just need to ensure it's properly initialized
-        msg!("Address {} added to blacklist", ctx.accounts.target_address.key());
+        let blacklist_entry = &mut ctx.accounts.blacklist_entry;
+        blacklist_entry.address = ctx.accounts.target_address.key();
+        blacklist_entry.reason_code = reason_code;
+        blacklist_entry.reference_hash = reference_hash;
+        blacklist_entry.listed_by = signer_key;
+        blacklist_entry.listed_at = Clock::get()?.unix_timestamp;
+
+        emit!(AddressBlacklisted {
+            mint: ctx.accounts.mint.key(),
+            address: blacklist_entry.address,
+            reason_code,
+            reference_hash,
+            authority: signer_key,
+            timestamp: blacklist_entry.listed_at,
+        });
         Ok(())
     }
     
@@ -114,51 +462,142 @@ pub mod transfer_hook_gatekeeper {
         let is_main_admin = signer_key == ctx.accounts.config.admin;
         let has_enum_role = ctx.accounts.asset_protection_role
             .as_ref()
-            .map(|role_account| role_account.user == signer_key && role_account.role == Role::AssetProtector)
+            .map(|role_account| role_account.is_active(signer_key, Role::AssetProtector))
             .unwrap_or(false);
-            
+        let has_registry_grant = has_registry_role(
+            &ctx.accounts.registry_role,
+            &ctx.accounts.config,
+            ctx.accounts.mint.key(),
+            signer_key,
+            Role::AssetProtector,
+        );
         require!(
-            has_enum_role || is_main_admin,
+            has_enum_role || has_registry_grant || is_main_admin,
             GatekeeperError::Unauthorized
         );
         
-        // The blacklist entry account is closed by the #[account(close)] constraint
-        msg!("Address removed from blacklist");
+        // Entries listed before reasons were recorded hold only the discriminator
+        let entry_info = ctx.accounts.blacklist_entry.to_account_info();
+        let (reason_code, listed_by) = if entry_info.data_len() > 8 {
+            let entry = BlacklistEntry::try_deserialize(&mut &entry_info.try_borrow_data()?[..])?;
+            (entry.reason_code, entry.listed_by)
+        } else {
+            (0, Pubkey::default())
+        };
+        close_program_account(&entry_info, &ctx.accounts.authority.to_account_info())?;
+
+        emit!(AddressUnblacklisted {
+            mint: ctx.accounts.mint.key(),
+            address: ctx.accounts.target_address.key(),
+            reason_code,
+            listed_by,
+            authority: signer_key,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
         Ok(())
     }
 
-    /// Add asset protector role to a user (admin only)
-    pub fn add_role(ctx: Context<AddRole>, user: Pubkey, role: Role) -> Result<()> {
+    /// Grant `role` to `user`; `valid_until == 0` never expires.
+    /// Callable by the main admin or an active holder of the role's admin role.
+    pub fn grant_role(
+        ctx: Context<GrantRole>,
+        user: Pubkey,
+        role: Role,
+        valid_from: i64,
+        valid_until: i64,
+    ) -> Result<()> {
         let authority_key = ctx.accounts.admin.key();
-        let is_main_admin = authority_key == ctx.accounts.config.admin;
-        let has_default_admin = ctx.accounts.default_admin_role
-            .as_ref()
-            .map(|r| r.user == authority_key && r.role == Role::DefaultAdmin)
-            .unwrap_or(false);
-        require!( is_main_admin || has_default_admin, GatekeeperError::Unauthorized);
+        require!(
+            can_administer_role(&ctx.accounts.config, &ctx.accounts.admin_role, authority_key, role),
+            GatekeeperError::Unauthorized
+        );
+        require!(
+            valid_until == 0 || valid_until > valid_from,
+            GatekeeperError::InvalidRoleValidity
+        );
         let user_role = &mut ctx.accounts.user_role;
         user_role.user = user;
-        user_role.role = role.clone();
+        user_role.role = role;
+        user_role.valid_from = valid_from;
+        user_role.valid_until = valid_until;
+        user_role.granted_by = authority_key;
+
+        emit!(RoleAssigned {
+            mint: ctx.accounts.mint.key(),
+            user,
+            role: format!("{:?}", role),
+            authority: authority_key,
+            valid_from,
+            valid_until,
+        });
         Ok(())
     }
     
-    /// Remove role from a user (admin only)
-    pub fn remove_role(ctx: Context<RemoveRole>, user: Pubkey, role: Role) -> Result<()> {
+    /// Revoke `role` from `user`; same authority as `grant_role`
+    pub fn revoke_role(ctx: Context<RevokeRole>, user: Pubkey, role: Role) -> Result<()> {
         let authority_key = ctx.accounts.admin.key();
-        let is_main_admin = authority_key == ctx.accounts.config.admin;
-        let has_default_admin = ctx.accounts.default_admin_role
-            .as_ref()
-            .map(|r| r.user == authority_key && r.role == Role::DefaultAdmin)
-            .unwrap_or(false);
+        require!(
+            can_administer_role(&ctx.accounts.config, &ctx.accounts.admin_role, authority_key, role),
+            GatekeeperError::Unauthorized
+        );
+        close_program_account(
+            &ctx.accounts.user_role.to_account_info(),
+            &ctx.accounts.admin.to_account_info(),
+        )?;
+        emit!(RoleRemoved {
+            mint: ctx.accounts.mint.key(),
+            user,
+            role: format!("{:?}", role),
+            authority: authority_key,
+        });
+        Ok(())
+    }
+
+    /// Give up a role held by the signer
+    pub fn renounce_role(ctx: Context<RenounceRole>, role: Role) -> Result<()> {
+        let user = ctx.accounts.user.key();
+        close_program_account(
+            &ctx.accounts.user_role.to_account_info(),
+            &ctx.accounts.user.to_account_info(),
+        )?;
+        emit!(RoleRemoved {
+            mint: ctx.accounts.mint.key(),
+            user,
+            role: format!("{:?}", role),
+            authority: user,
+        });
+        Ok(())
+    }
+
+    /// Change which role administers `role` (main admin only)
+    pub fn set_role_admin(ctx: Context<SetRoleAdmin>, role: Role, admin_role: Role) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let previous_admin_role = config.role_admins[role as usize];
+        config.role_admins[role as usize] = admin_role;
 
-            require!(is_main_admin || has_default_admin, GatekeeperError::Unauthorized);
-        // PDA account will be closed automatically due to close constraint
+        emit!(RoleAdminChanged {
+            mint: ctx.accounts.mint.key(),
+            role,
+            previous_admin_role,
+            new_admin_role: admin_role,
+        });
         Ok(())
     }
 
 
     /// The main transfer hook execution function
-    pub fn transfer_hook(ctx: Context<TransferHook>, _amount: u64) -> Result<()> {
+    pub fn transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+        // Court-ordered seizures by the permanent delegate bypass all holder checks
+        let owner_key = ctx.accounts.owner.key();
+        if owner_key == ctx.accounts.config.seizure_authority {
+            return Ok(());
+        }
+
+        require!(!ctx.accounts.config.is_paused, GatekeeperError::TransfersPaused);
+        let mode = ctx.accounts.config.compliance_mode;
+        // Releases from redemption escrow skip source-side checks; the receiving holder is still checked
+        let from_escrow = owner_key == ctx.accounts.config.redemption_authority;
+
         // Validate that the owner matches the source token account owner
         require_keys_eq!(
             ctx.accounts.owner.key(),
@@ -166,14 +605,40 @@ pub mod transfer_hook_gatekeeper {
             GatekeeperError::Unauthorized
         );
     
-        // Check if source blacklist PDA exists and has data (meaning the address is blacklisted)
-        if ctx.accounts.source_blacklist_entry.data_len() >= 8 { // Has discriminator = blacklisted
-            return err!(GatekeeperError::AddressBlacklisted);
+        if mode != ComplianceMode::Allowlist {
+            // Check if source blacklist PDA exists and has data (meaning the address is blacklisted)
+            if !from_escrow && ctx.accounts.source_blacklist_entry.data_len() >= 8 { // Has discriminator = blacklisted
+                return err!(GatekeeperError::AddressBlacklisted);
+            }
+        
+            // Check if destination blacklist PDA exists and has data
+            if ctx.accounts.destination_blacklist_entry.data_len() >= 8 { // Has discriminator = blacklisted
+                return err!(GatekeeperError::AddressBlacklisted);
+            }
         }
-    
-        // Check if destination blacklist PDA exists and has data
-        if ctx.accounts.destination_blacklist_entry.data_len() >= 8 { // Has discriminator = blacklisted
-            return err!(GatekeeperError::AddressBlacklisted);
+
+        if mode != ComplianceMode::Denylist {
+            // Both owners need a current KYC entry from an allowed jurisdiction
+            let now = Clock::get()?.unix_timestamp;
+            let config = &ctx.accounts.config;
+            if !from_escrow {
+                let source_kyc = load_kyc_entry(&ctx.accounts.source_kyc_entry, config, now)?;
+                require!(
+                    amount <= config.tier_limits[source_kyc.tier as usize].max_transfer_amount,
+                    GatekeeperError::TierLimitExceeded
+                );
+            }
+
+            // Deposits into redemption escrow have no KYC'd destination owner
+            if ctx.accounts.destination_token.owner != config.redemption_authority {
+                let destination_kyc = load_kyc_entry(&ctx.accounts.destination_kyc_entry, config, now)?;
+                // The destination balance already includes the transferred amount
+                require!(
+                    ctx.accounts.destination_token.amount
+                        <= config.tier_limits[destination_kyc.tier as usize].max_holding_amount,
+                    GatekeeperError::TierLimitExceeded
+                );
+            }
         }
         
         // Check if the transfer amount exceeds the non-delegated portion
@@ -181,11 +646,11 @@ pub mod transfer_hook_gatekeeper {
         // IMPORTANT: The transfer hook is called DURING transfer execution, so the token account
         // balance already has the transfer amount deducted. We need to add it back to get
         // the actual pre-transfer balance for validation.
-        let actual_balance = ctx.accounts.source_token.amount.checked_add(_amount).unwrap_or(ctx.accounts.source_token.amount);
+        let actual_balance = ctx.accounts.source_token.amount.checked_add(amount).unwrap_or(ctx.accounts.source_token.amount);
         let available_for_transfer = actual_balance.saturating_sub(ctx.accounts.source_token.delegated_amount);
         
         // Only block transfers that exceed the available non-delegated amount
-        if _amount > available_for_transfer {
+        if amount > available_for_transfer {
             return err!(GatekeeperError::InsufficientAvailableTokens);
         }
         Ok(())
@@ -208,6 +673,182 @@ pub mod transfer_hook_gatekeeper {
     }
 }
 
+/// Extra accounts the transfer hook expects after the five standard `Execute` accounts
+fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    Ok(vec![
+        // index 5: source blacklist PDA
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: "blacklist".as_bytes().to_vec() },
+                Seed::AccountKey { index: 1 }, // The mint account
+                Seed::AccountKey { index: 3 }, // source token account owner
+            ],
+            false, // is_signer
+            false, // is_writable
+        )?,
+        // index 6: destination blacklist PDA
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: "blacklist".as_bytes().to_vec() },
+                Seed::AccountKey { index: 1 }, // The mint account
+                Seed::AccountData { account_index: 2, data_index: 32, length: 32 }, // destination token account owner
+            ],
+            false, // is_signer
+            false, // is_writable
+        )?,
+        // index 7: gatekeeper config PDA (per-mint pause flag)
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: "config".as_bytes().to_vec() },
+                Seed::AccountKey { index: 1 }, // The mint account
+            ],
+            false, // is_signer
+            false, // is_writable
+        )?,
+        // index 8: source KYC PDA
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: "kyc".as_bytes().to_vec() },
+                Seed::AccountKey { index: 1 }, // The mint account
+                Seed::AccountData { account_index: 0, data_index: 32, length: 32 }, // source token account owner
+            ],
+            false, // is_signer
+            false, // is_writable
+        )?,
+        // index 9: destination KYC PDA
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: "kyc".as_bytes().to_vec() },
+                Seed::AccountKey { index: 1 }, // The mint account
+                Seed::AccountData { account_index: 2, data_index: 32, length: 32 }, // destination token account owner
+            ],
+            false, // is_signer
+            false, // is_writable
+        )?,
+    ])
+}
+
+/// Grow a program-owned account to `new_size`, topping up rent from `payer` first
+fn realloc_with_rent<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_size: usize,
+) -> Result<()> {
+    if account.data_len() >= new_size {
+        return Ok(());
+    }
+    let shortfall = Rent::get()?
+        .minimum_balance(new_size)
+        .saturating_sub(account.lamports());
+    if shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    account.realloc(new_size, false)?;
+    Ok(())
+}
+
+/// Close a program-owned account without deserializing it, so PDAs in a legacy layout can still be removed
+fn close_program_account<'info>(account: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+    let lamports = account.lamports();
+    **destination.try_borrow_mut_lamports()? = destination.lamports().checked_add(lamports).unwrap();
+    **account.try_borrow_mut_lamports()? = 0;
+    account.assign(&System::id());
+    account.realloc(0, false)?;
+    Ok(())
+}
+
+/// True if `authority` may grant and revoke `role`: the main admin, or an active holder of the role's admin role
+fn can_administer_role(
+    config: &Config,
+    admin_role: &Option<Account<UserRole>>,
+    authority: Pubkey,
+    role: Role,
+) -> bool {
+    authority == config.admin
+        || admin_role
+            .as_ref()
+            .map(|r| r.is_active(authority, config.role_admins[role as usize]))
+            .unwrap_or(false)
+}
+
+/// Token program's `UserRole` layout; its account discriminator matches the local `UserRole`
+#[derive(AnchorDeserialize)]
+struct RegistryUserRole {
+    user: Pubkey,
+    role: Role,
+    _mint_allowance: u64,
+    valid_from: i64,
+    valid_until: i64,
+    granted_by: Pubkey,
+}
+
+/// True if `account` is an active `role` grant for `user` in the token program's role registry
+fn has_registry_role(
+    account: &Option<UncheckedAccount>,
+    config: &Config,
+    mint: Pubkey,
+    user: Pubkey,
+    role: Role,
+) -> bool {
+    let Some(account) = account else {
+        return false;
+    };
+    if account.owner != &config.role_registry {
+        return false;
+    }
+    let (expected, _) = Pubkey::find_program_address(
+        &[b"user_role", mint.as_ref(), user.as_ref(), &[role as u8]],
+        &config.role_registry,
+    );
+    if account.key() != expected {
+        return false;
+    }
+
+    let Ok(data) = account.try_borrow_data() else {
+        return false;
+    };
+    if !data.starts_with(UserRole::DISCRIMINATOR.as_ref()) {
+        return false;
+    }
+    let Ok(grant) = RegistryUserRole::deserialize(&mut &data[8..]) else {
+        return false;
+    };
+    UserRole {
+        user: grant.user,
+        role: grant.role,
+        valid_from: grant.valid_from,
+        valid_until: grant.valid_until,
+        granted_by: grant.granted_by,
+    }
+    .is_active(user, role)
+}
+
+/// Load a KYC entry passed to the hook and check it is current and from an allowed jurisdiction
+fn load_kyc_entry(entry: &UncheckedAccount, config: &Config, now: i64) -> Result<KycEntry> {
+    require!(
+        entry.data_len() >= 8 && entry.owner == &crate::ID,
+        GatekeeperError::KycRequired
+    );
+    let kyc = KycEntry::try_deserialize(&mut &entry.try_borrow_data()?[..])?;
+    require!(kyc.expires_at > now, GatekeeperError::KycExpired);
+    require!((kyc.tier as usize) < MAX_KYC_TIERS, GatekeeperError::InvalidKycTier);
+    require!(
+        !config.blocked_jurisdictions.contains(&kyc.country_code),
+        GatekeeperError::JurisdictionBlocked
+    );
+    Ok(kyc)
+}
+
 /// Initialize extra account meta list
 #[derive(Accounts)]
 pub struct InitializeExtraAccountMetaList<'info> {
@@ -224,6 +865,24 @@ pub struct InitializeExtraAccountMetaList<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Rewrite the extra account meta list of an existing mint
+#[derive(Accounts)]
+pub struct UpdateExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(has_one = admin, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    /// CHECK: ExtraAccountMetaList Account, must use these seeds
+    #[account(
+        mut,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
 /// Initialize the gatekeeper configuration
 #[derive(Accounts)]
 pub struct Initialize<'info> {
@@ -234,7 +893,7 @@ pub struct Initialize<'info> {
     #[account(
         init,
         payer = payer,
-        space = 8 + 32, // discriminator + admin pubkey
+        space = CONFIG_SPACE,
         seeds = [b"config", mint.key().as_ref()],
         bump
     )]
@@ -245,6 +904,23 @@ pub struct Initialize<'info> {
 
 
 
+/// Upgrade a legacy gatekeeper configuration
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: Legacy config PDA that no longer deserializes as `Config`; checked by seeds and owner, admin verified in the handler
+    #[account(
+        mut,
+        seeds = [b"config", mint.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub config: UncheckedAccount<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
 /// Add an address to the blacklist
 #[derive(Accounts)]
 pub struct AddToBlacklist<'info> {
@@ -260,7 +936,7 @@ pub struct AddToBlacklist<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8, // Just the discriminator
+        space = 8 + 32 + 1 + 32 + 32 + 8, // discriminator + address + reason code + reference hash + listed_by + listed_at
         seeds = [b"blacklist", mint.key().as_ref(), target_address.key().as_ref()],
         bump
     )]
@@ -272,6 +948,8 @@ pub struct AddToBlacklist<'info> {
         bump
     )]
     pub asset_protection_role: Option<Account<'info, UserRole>>,
+    /// CHECK: Optional grant from the token program's role registry, verified by owner and seeds
+    pub registry_role: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
 }
 
@@ -287,13 +965,14 @@ pub struct RemoveFromBlacklist<'info> {
     pub authority: Signer<'info>,
     /// CHECK: The address being removed from blacklist
     pub target_address: AccountInfo<'info>,
+    /// CHECK: Blacklist entry PDA, checked by seeds and owner; may still use the legacy empty layout
     #[account(
         mut,
         seeds = [b"blacklist", mint.key().as_ref(), target_address.key().as_ref()],
         bump,
-        close = authority
+        owner = crate::ID
     )]
-    pub blacklist_entry: Account<'info, BlacklistEntry>,
+    pub blacklist_entry: UncheckedAccount<'info>,
     pub mint: InterfaceAccount<'info, Mint>,
     /// Optional: Role account for enum-based access
     #[account(
@@ -301,12 +980,119 @@ pub struct RemoveFromBlacklist<'info> {
         bump
     )]
     pub asset_protection_role: Option<Account<'info, UserRole>>,
+    /// CHECK: Optional grant from the token program's role registry, verified by owner and seeds
+    pub registry_role: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct SetComplianceRules<'info> {
+    #[account(mut, has_one = admin, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+#[derive(Accounts)]
+pub struct SetComplianceMode<'info> {
+    #[account(mut, has_one = admin, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Add a KYC entry for a wallet
+#[derive(Accounts)]
+pub struct AddKycEntry<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: The wallet being verified
+    pub target_address: AccountInfo<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32 + 8 + 8 + 1 + 2, // discriminator + owner + verified_at + expires_at + tier + country code
+        seeds = [b"kyc", mint.key().as_ref(), target_address.key().as_ref()],
+        bump
+    )]
+    pub kyc_entry: Account<'info, KycEntry>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// Optional: Role account for enum-based access
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), authority.key().as_ref(), &[Role::ComplianceOfficer as u8]],
+        bump
+    )]
+    pub compliance_role: Option<Account<'info, UserRole>>,
+    /// CHECK: Optional grant from the token program's role registry, verified by owner and seeds
+    pub registry_role: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Update a wallet's KYC entry
+#[derive(Accounts)]
+pub struct UpdateKycEntry<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    pub authority: Signer<'info>,
+    /// CHECK: The wallet being re-verified
+    pub target_address: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"kyc", mint.key().as_ref(), target_address.key().as_ref()],
+        bump
+    )]
+    pub kyc_entry: Account<'info, KycEntry>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// Optional: Role account for enum-based access
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), authority.key().as_ref(), &[Role::ComplianceOfficer as u8]],
+        bump
+    )]
+    pub compliance_role: Option<Account<'info, UserRole>>,
+    /// CHECK: Optional grant from the token program's role registry, verified by owner and seeds
+    pub registry_role: Option<UncheckedAccount<'info>>,
+}
+
+/// Remove a KYC entry for a wallet
+#[derive(Accounts)]
+pub struct RemoveKycEntry<'info> {
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: The wallet whose verification is revoked
+    pub target_address: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"kyc", mint.key().as_ref(), target_address.key().as_ref()],
+        bump,
+        close = authority
+    )]
+    pub kyc_entry: Account<'info, KycEntry>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// Optional: Role account for enum-based access
+    #[account(
+        seeds = [b"user_role", mint.key().as_ref(), authority.key().as_ref(), &[Role::ComplianceOfficer as u8]],
+        bump
+    )]
+    pub compliance_role: Option<Account<'info, UserRole>>,
+    /// CHECK: Optional grant from the token program's role registry, verified by owner and seeds
+    pub registry_role: Option<UncheckedAccount<'info>>,
 }
 
-/// Add asset protector role to a user (admin only)
 #[derive(Accounts)]
 #[instruction(user: Pubkey, role: Role)]
-pub struct AddRole<'info> {
+pub struct GrantRole<'info> {
     #[account(
         seeds = [b"config", mint.key().as_ref()],
         bump
@@ -318,24 +1104,23 @@ pub struct AddRole<'info> {
     #[account(
         init,
         payer = admin,
-        space = 8 + 32 + 1, // discriminator + pubkey + role enum
-        seeds = [b"user_role", mint.key().as_ref(), user.as_ref(), &[role.clone() as u8]],
+        space = 8 + 32 + 1 + 8 + 8 + 32, // discriminator + pubkey + role enum + validity window + granted_by
+        seeds = [b"user_role", mint.key().as_ref(), user.as_ref(), &[role as u8]],
         bump
     )]
     pub user_role: Account<'info, UserRole>,
-    /// Optional: Role account for checking if admin has DefaultAdmin role
+    /// Optional: signer's grant of the admin role for `role`
     #[account(
-        seeds = [b"user_role", mint.key().as_ref(), admin.key().as_ref(), &[Role::DefaultAdmin as u8]],
+        seeds = [b"user_role", mint.key().as_ref(), admin.key().as_ref(), &[config.role_admins[role as usize] as u8]],
         bump
     )]
-    pub default_admin_role: Option<Account<'info, UserRole>>,
+    pub admin_role: Option<Account<'info, UserRole>>,
     pub system_program: Program<'info, System>,
 }
 
-/// Remove role from a user (admin only) - Generic for any role type
 #[derive(Accounts)]
 #[instruction(user: Pubkey, role: Role)]
-pub struct RemoveRole<'info> {
+pub struct RevokeRole<'info> {
     #[account(
         seeds = [b"config", mint.key().as_ref()],
         bump
@@ -344,19 +1129,44 @@ pub struct RemoveRole<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
     pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: Role grant PDA, checked by seeds and owner; may predate the validity window fields
     #[account(
         mut,
         seeds = [b"user_role", mint.key().as_ref(), user.as_ref(), &[role as u8]],
         bump,
-        close = admin
+        owner = crate::ID
     )]
-    pub user_role: Account<'info, UserRole>,
-    /// Optional: Role account for checking if admin has DefaultAdmin role
+    pub user_role: UncheckedAccount<'info>,
+    /// Optional: signer's grant of the admin role for `role`
     #[account(
-        seeds = [b"user_role", mint.key().as_ref(), admin.key().as_ref(), &[Role::DefaultAdmin as u8]],
+        seeds = [b"user_role", mint.key().as_ref(), admin.key().as_ref(), &[config.role_admins[role as usize] as u8]],
         bump
     )]
-    pub default_admin_role: Option<Account<'info, UserRole>>,
+    pub admin_role: Option<Account<'info, UserRole>>,
+}
+
+#[derive(Accounts)]
+#[instruction(role: Role)]
+pub struct RenounceRole<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: Role grant PDA, checked by seeds and owner; may predate the validity window fields
+    #[account(
+        mut,
+        seeds = [b"user_role", mint.key().as_ref(), user.key().as_ref(), &[role as u8]],
+        bump,
+        owner = crate::ID
+    )]
+    pub user_role: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetRoleAdmin<'info> {
+    #[account(mut, has_one = admin, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
 }
 
 
@@ -380,6 +1190,23 @@ pub struct TransferHook<'info> {
     pub source_blacklist_entry: UncheckedAccount<'info>,
     /// CHECK: Destination blacklist PDA - this account may not exist (which is OK)
     pub destination_blacklist_entry: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config", mint.key().as_ref()],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    /// CHECK: Source owner KYC PDA - this account may not exist (checked against the compliance mode)
+    #[account(
+        seeds = [b"kyc", mint.key().as_ref(), source_token.owner.as_ref()],
+        bump
+    )]
+    pub source_kyc_entry: UncheckedAccount<'info>,
+    /// CHECK: Destination owner KYC PDA - this account may not exist (checked against the compliance mode)
+    #[account(
+        seeds = [b"kyc", mint.key().as_ref(), destination_token.owner.as_ref()],
+        bump
+    )]
+    pub destination_kyc_entry: UncheckedAccount<'info>,
 }
 
 #[derive(Accounts)]
@@ -390,29 +1217,269 @@ pub struct SetAdmin<'info> {
     pub admin: Signer<'info>,
     pub mint: InterfaceAccount<'info, Mint>,
 }
+
+#[derive(Accounts)]
+pub struct SetPauseState<'info> {
+    #[account(mut, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub authority: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(mut, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub pending_admin: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAdminTransfer<'info> {
+    #[account(mut, has_one = admin, seeds = [b"config", mint.key().as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+}
 /// Configuration account for the gatekeeper
 #[account]
 pub struct Config {
     pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    /// Token program PDA allowed to toggle the pause flag by CPI
+    pub pause_authority: Pubkey,
+    /// Token program's permanent delegate PDA, exempt from hook checks
+    pub seizure_authority: Pubkey,
+    /// Token program PDA that owns redemption escrows, exempt from hook checks
+    pub redemption_authority: Pubkey,
+    /// Token program whose `[b"user_role", mint, user, role]` grants are honoured here
+    pub role_registry: Pubkey,
+    pub is_paused: bool,
+    pub compliance_mode: ComplianceMode,
+    pub tier_limits: [TierLimit; MAX_KYC_TIERS],
+    pub blocked_jurisdictions: Vec<[u8; 2]>,
+    /// Admin role of each role, indexed by `Role as usize`
+    pub role_admins: [Role; ROLE_COUNT],
+}
+
+/// Per-tier transfer and holding caps in base units
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TierLimit {
+    pub max_transfer_amount: u64,
+    pub max_holding_amount: u64,
+}
+
+impl Default for TierLimit {
+    fn default() -> Self {
+        Self {
+            max_transfer_amount: u64::MAX,
+            max_holding_amount: u64::MAX,
+        }
+    }
+}
+
+/// Which address lists the transfer hook enforces
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ComplianceMode {
+    Denylist,
+    Allowlist,
+    Both,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Role {
     SupplyController,
     AssetProtector,
     FeeController,
     DefaultAdmin,
+    Auditor,
+    ComplianceOfficer,
 }
 
 #[account]
 pub struct UserRole {
     pub user: Pubkey,
     pub role: Role,
+    /// Grant is inactive before this timestamp
+    pub valid_from: i64,
+    /// Grant is inactive from this timestamp on; 0 means no expiry
+    pub valid_until: i64,
+    pub granted_by: Pubkey,
+}
+
+impl UserRole {
+    /// True if this grant is held by `user` for `role` and is within its validity window
+    pub fn is_active(&self, user: Pubkey, role: Role) -> bool {
+        let now = match Clock::get() {
+            Ok(clock) => clock.unix_timestamp,
+            Err(_) => return false,
+        };
+        self.user == user
+            && self.role == role
+            && now >= self.valid_from
+            && (self.valid_until == 0 || now < self.valid_until)
+    }
+}
+
+/// Marks an address as blacklisted and records who listed it, when and why
+#[account]
+pub struct BlacklistEntry {
+    pub address: Pubkey,
+    pub reason_code: u8,
+    /// Hash of the off-chain case reference
+    pub reference_hash: [u8; 32],
+    pub listed_by: Pubkey,
+    pub listed_at: i64,
 }
 
-/// Empty account that marks an address as blacklisted
+/// Marks a wallet as KYC-verified for a mint
 #[account]
-pub struct BlacklistEntry {}
+pub struct KycEntry {
+    pub owner: Pubkey,
+    pub verified_at: i64,
+    pub expires_at: i64,
+    pub tier: u8,
+    /// ISO 3166-1 alpha-2 country code
+    pub country_code: [u8; 2],
+}
+
+#[event]
+pub struct ExtraAccountMetaListUpdated {
+    pub mint: Pubkey,
+    pub extra_accounts: u8,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ConfigMigrated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct RoleAssigned {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub role: String,
+    pub authority: Pubkey,
+    pub valid_from: i64,
+    pub valid_until: i64,
+}
+
+#[event]
+pub struct RoleRemoved {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub role: String,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct RoleAdminChanged {
+    pub mint: Pubkey,
+    pub role: Role,
+    pub previous_admin_role: Role,
+    pub new_admin_role: Role,
+}
+
+#[event]
+pub struct AdminTransferProposed {
+    pub mint: Pubkey,
+    pub current_admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferAccepted {
+    pub mint: Pubkey,
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferCancelled {
+    pub mint: Pubkey,
+    pub admin: Pubkey,
+    pub cancelled_admin: Pubkey,
+}
+
+#[event]
+pub struct TransferPauseUpdated {
+    pub mint: Pubkey,
+    pub is_paused: bool,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct AddressBlacklisted {
+    pub mint: Pubkey,
+    pub address: Pubkey,
+    pub reason_code: u8,
+    pub reference_hash: [u8; 32],
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AddressUnblacklisted {
+    pub mint: Pubkey,
+    pub address: Pubkey,
+    pub reason_code: u8,
+    pub listed_by: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ComplianceModeUpdated {
+    pub mint: Pubkey,
+    pub old_mode: ComplianceMode,
+    pub new_mode: ComplianceMode,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct KycEntryAdded {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub tier: u8,
+    pub country_code: [u8; 2],
+    pub expires_at: i64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct KycEntryUpdated {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub tier: u8,
+    pub country_code: [u8; 2],
+    pub expires_at: i64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct TierLimitUpdated {
+    pub mint: Pubkey,
+    pub tier: u8,
+    pub max_transfer_amount: u64,
+    pub max_holding_amount: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct BlockedJurisdictionsUpdated {
+    pub mint: Pubkey,
+    pub jurisdictions: Vec<[u8; 2]>,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct KycEntryRemoved {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub authority: Pubkey,
+}
 
 #[error_code]
 pub enum GatekeeperError {
@@ -422,4 +1489,26 @@ pub enum GatekeeperError {
     Unauthorized,
     #[msg("Transfer amount exceeds available non-delegated tokens.")]
     InsufficientAvailableTokens,
+    #[msg("Invalid admin address.")]
+    InvalidAdmin,
+    #[msg("No admin transfer is pending.")]
+    NoPendingAdmin,
+    #[msg("Transfers are paused for this mint.")]
+    TransfersPaused,
+    #[msg("Source or destination owner is not KYC-verified.")]
+    KycRequired,
+    #[msg("KYC verification has expired.")]
+    KycExpired,
+    #[msg("Invalid KYC tier.")]
+    InvalidKycTier,
+    #[msg("Jurisdiction is blocked for this mint.")]
+    JurisdictionBlocked,
+    #[msg("Amount exceeds the limit for this KYC tier.")]
+    TierLimitExceeded,
+    #[msg("Too many blocked jurisdictions.")]
+    TooManyJurisdictions,
+    #[msg("Role validity window is invalid.")]
+    InvalidRoleValidity,
+    #[msg("Config already uses the current layout.")]
+    ConfigAlreadyMigrated,
 }
\ No newline at end of file